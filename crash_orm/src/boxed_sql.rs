@@ -2,12 +2,14 @@
 //!
 //! Utility for building a full query.
 
+use std::error::Error;
 use std::sync::Arc;
 
-use postgres::types::ToSql;
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, IsNull, Kind, ToSql, Type};
 
 use crate::column_value::UntypedColumnValue;
-use crate::prelude::ColumnType;
+use crate::prelude::{ColumnType, Entity, EntityColumn, VirtualColumn};
 
 /// Struct containing a part of a query with raw sql and values prepared for tokio-postgres.
 #[derive(Clone, Debug)]
@@ -46,6 +48,66 @@ impl BoxedSql {
     pub fn modify<F: FnOnce(&String) -> String>(&mut self, f: F) {
         self.sql = f(&self.sql);
     }
+
+    /// Returns true, if both have the same SQL and values, e.g. the same expression used twice.
+    pub(crate) fn same_expression(&self, other: &BoxedSql) -> bool {
+        self.sql == other.sql && format!("{:?}", self.values) == format!("{:?}", other.values)
+    }
+
+    /// Combines plain values into a single array parameter, e.g. for `= ANY(_$i)`.
+    ///
+    /// Returns [None] if any element is not a single parameter, e.g. a column or NULL.
+    pub(crate) fn array(elements: Vec<BoxedSql>) -> Option<BoxedSql> {
+        if !elements.iter().all(|element| element.sql == "_$i" && element.values.len() == 1) {
+            return None;
+        }
+
+        let values = elements.into_iter().flat_map(|element| element.values).collect();
+        Some(BoxedSql::new(String::from("_$i"), vec![Arc::new(Box::new(ArrayValue(values)))]))
+    }
+}
+
+/// Array parameter of already boxed values.
+///
+/// The element type is taken from the type Postgres infers for the parameter.
+#[derive(Debug)]
+struct ArrayValue(Vec<Arc<Box<dyn ToSql + Sync + Send + 'static>>>);
+
+impl ToSql for ArrayValue {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let Kind::Array(member) = ty.kind() else {
+            return Err(format!("expected an array type, got {}", ty).into());
+        };
+
+        out.extend_from_slice(&1i32.to_be_bytes());
+        let has_nulls = out.len();
+        out.extend_from_slice(&0i32.to_be_bytes());
+        out.extend_from_slice(&member.oid().to_be_bytes());
+        out.extend_from_slice(&i32::try_from(self.0.len())?.to_be_bytes());
+        out.extend_from_slice(&1i32.to_be_bytes());
+
+        for value in &self.0 {
+            let start = out.len();
+            out.extend_from_slice(&0i32.to_be_bytes());
+
+            let length = match (**value).to_sql_checked(member, out)? {
+                IsNull::Yes => {
+                    out[has_nulls..has_nulls + 4].copy_from_slice(&1i32.to_be_bytes());
+                    -1
+                }
+                IsNull::No => i32::try_from(out.len() - start - 4)?,
+            };
+            out[start..start + 4].copy_from_slice(&length.to_be_bytes());
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Array(_))
+    }
+
+    to_sql_checked!();
 }
 
 /// Trait for converting any type that implements [ColumnType] and [UntypedColumnValue] into a [BoxedSql].
@@ -71,4 +133,28 @@ impl<'a> IntoSql<String> for &'a str {
     fn into_boxed_sql(&self) -> BoxedSql {
         self.to_string().into_boxed_sql()
     }
-}
\ No newline at end of file
+}
+
+impl<T: ColumnType, U: Entity> IntoSql<T> for EntityColumn<T, U> {
+    fn into_boxed_sql(&self) -> BoxedSql {
+        self.get_sql()
+    }
+}
+
+impl<T: ColumnType, U: Entity> IntoSql<T> for EntityColumn<Option<T>, U> {
+    fn into_boxed_sql(&self) -> BoxedSql {
+        self.get_sql()
+    }
+}
+
+impl<T: ColumnType, U: Entity> IntoSql<T> for VirtualColumn<T, U> {
+    fn into_boxed_sql(&self) -> BoxedSql {
+        self.get_sql()
+    }
+}
+
+impl<T: ColumnType, U: Entity> IntoSql<T> for VirtualColumn<Option<T>, U> {
+    fn into_boxed_sql(&self) -> BoxedSql {
+        self.get_sql()
+    }
+}
@@ -18,6 +18,11 @@ impl<T: ColumnType, U: Entity> Column<T, U> for EntityColumn<Option<T>, U> {}
 pub trait UntypedColumn<U: Entity>: Sync {
     /// Internal function to get a sql representation of the column
     fn get_sql(&self) -> BoxedSql;
+
+    /// Internal function to get a sql representation of the column in the selected columns of a query
+    fn get_select_sql(&self) -> BoxedSql {
+        self.get_sql()
+    }
 }
 
 impl<T: ColumnType, U: Entity> UntypedColumn<U> for EntityColumn<T, U> {
@@ -30,4 +35,8 @@ impl<T: ColumnType, U: Entity> UntypedColumn<U> for VirtualColumn<T, U> {
     fn get_sql(&self) -> BoxedSql {
         self.get_sql()
     }
+
+    fn get_select_sql(&self) -> BoxedSql {
+        self.get_select_sql()
+    }
 }
@@ -19,14 +19,21 @@
 //! | chrono::NaiveTime     | TIME                                                      |
 //! | uuid::Uuid            | UUID                                                      |
 //! | serde_json::Value     | JSON                                                      |
+//! | Vec<u8>               | BYTEA                                                     |
+//! | Vec<T>                | Array of T, e.g. TEXT[] for Vec<String>                   |
+//! | crash_orm::Range<T>   | Range of T, see [here](crate::range)                      |
 //! | crash_orm::OneToOne   | Primary key of the referenced table (Foreign Key)         |
 //! | crash_orm::ManyToOne  | Primary key of the referenced table (Foreign Key)         |
 //!
+//! More types like INTERVAL, INET or MONEY are available with feature flags, see [here](crate::types).
+//!
 //! Those are not all valid types.
 //! A valid type must implement ToSql and FromSql from tokio-postgres.
+//! Other types can be used by implementing [SqlType], see [here](crate::sql_type).
 //!
 //! To make a column nullable, just put the type in an Option.
 
+#[cfg(feature = "json")]
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -87,6 +94,8 @@ simple_column_value!(chrono::NaiveDate);
 #[cfg(feature = "with-chrono")]
 simple_column_value!(chrono::NaiveTime);
 simple_column_value!(String);
+#[cfg(feature = "json")]
+simple_column_value!(serde_json::Value);
 #[cfg(feature = "with-eui48")]
 simple_column_value!(eui48::MacAddress);
 #[cfg(feature = "with-time")]
@@ -104,12 +113,31 @@ simple_column_value!(geo_types::Rect);
 #[cfg(feature = "with-geo-types")]
 simple_column_value!(geo_types::LineString);
 
+#[cfg(feature = "json")]
 impl<JSON: serde::Serialize + serde::de::DeserializeOwned + Debug + Clone + Send + Sync + 'static> UntypedColumnValue for TypedJson<JSON> {
     fn get_sql(&self) -> BoxedSql {
         BoxedSql::new("_$i".to_string(), vec![Arc::new(Box::new(self.clone()))])
     }
 }
 
+impl<T: ColumnType + Clone> UntypedColumnValue for Vec<T> {
+    fn get_sql(&self) -> BoxedSql {
+        BoxedSql::new("_$i".to_string(), vec![Arc::new(Box::new(self.clone()))])
+    }
+}
+
+impl<T: RangeElement> UntypedColumnValue for Range<T> {
+    fn get_sql(&self) -> BoxedSql {
+        BoxedSql::new("_$i".to_string(), vec![Arc::new(Box::new(self.clone()))])
+    }
+}
+
+impl<S: SqlType> UntypedColumnValue for S {
+    fn get_sql(&self) -> BoxedSql {
+        BoxedSql::new("_$i".to_string(), vec![Arc::new(Box::new(self.clone()))])
+    }
+}
+
 impl<T: ColumnType, U: Entity> UntypedColumnValue for VirtualColumn<T, U> {
     fn get_sql(&self) -> BoxedSql {
         self.get_sql()
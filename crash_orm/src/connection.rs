@@ -3,19 +3,65 @@
 //! All connections that should be used by the ORM must implement [DatabaseConnection].
 //!
 //! The default implementation for a connection with tokio-postgres is [CrashOrmDatabaseConnection].
+//!
+//! ## Capture Mode
+//! A [CrashOrmDatabaseConnection] can be switched into capture mode with [RecordingDatabaseConnection::recording].
+//! In capture mode, SELECT statements are still executed, but all other statements are only recorded.
+//! This is useful to preview the SQL of a [TableDefinition](crate::schema::TableDefinition) or a migration.
+//!
+//! A statement is executed, if it starts with SELECT, WITH, VALUES, TABLE or SHOW and all of its keywords are allowed
+//! in a read-only statement. It is recorded, if it
+//! - modifies data in a WITH, e.g. `WITH moved AS (DELETE ...) SELECT ...`
+//! - creates a table with `SELECT ... INTO`
+//! - contains any other command, like `DROP` or `ALTER`
+//! - changes a sequence with `nextval` or `setval`
+//! - contains multiple statements, escape strings or dollar quotes
+//!
+//! Comments are ignored. Scripts executed with [execute_batch](DatabaseConnection::execute_batch) are always recorded.
+//!
+//! Side effects of other functions, e.g. user-defined functions called by a SELECT, are not detected.
+//!
+//! Recorded statements return no rows, also with RETURNING. [Entity::insert](crate::entity::Entity::insert)
+//! keeps the primary key of the entity in capture mode. Recorded statements also affect no rows,
+//! so [Entity::update](crate::entity::Entity::update) and [Entity::remove](crate::entity::Entity::remove)
+//! do not return [Error::NotFound](crate::Error::NotFound) in capture mode.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! use crash_orm::postgres::types::Type;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! let recording = conn.recording();
+//! TableDefinition::new("capture_mode_example")
+//!     .add_column(ColumnDefinition::new("id", Type::INT4, false).primary()).unwrap()
+//!     .apply(&recording).await.unwrap();
+//!
+//! let statements = recording.take_recorded_statements();
+//! assert_eq!(statements[0].statement, "CREATE TABLE public.capture_mode_example(id int4 NOT NULL,PRIMARY KEY (id))");
+//! # });
+//! ```
 
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use tokio_postgres::{Client, Row, Socket};
+use tokio_postgres::{Client, Row, Socket, Transaction};
 use tokio_postgres::tls::MakeTlsConnect;
 use tokio_postgres::types::ToSql;
 
+use keywords::KEYWORDS;
+
+mod keywords;
+
 /// Trait required to be implemented for a connection to be used by the ORM.
 ///
 /// The default implementation that should be used is [CrashOrmDatabaseConnection].
 ///
-/// You can also just use the default tokio-postgres [Client], this trait is implemented for that as well.
+/// You can also just use the default tokio-postgres [Client] or a [Transaction], this trait is implemented for those as well.
+///
+/// Implementations should convert errors of Postgres with [Error::from_postgres](crate::Error::from_postgres).
 pub trait DatabaseConnection: Sync {
     /// Method used to only retrieve a single row of a query result.
     fn query_single(
@@ -39,11 +85,74 @@ pub trait DatabaseConnection: Sync {
         statement: &str,
         params: &[&(dyn ToSql + Sync)],
     ) -> impl Future<Output = crate::Result<u64>> + Send;
+
+    /// Method used to execute multiple statements separated by semicolons without parameters.
+    ///
+    /// The default implementation only supports a single statement.
+    fn execute_batch(
+        &self,
+        statements: &str,
+    ) -> impl Future<Output = crate::Result<()>> + Send {
+        async move {
+            self.execute_query(statements, &[]).await?;
+            Ok(())
+        }
+    }
+
+    /// Returns whether this connection is in capture mode, see [RecordingDatabaseConnection].
+    ///
+    /// The default implementation returns false.
+    fn is_recording(&self) -> bool {
+        false
+    }
+}
+
+/// Trait implemented for connections supporting capture mode.
+///
+/// Required for dry runs of migrations, see [CrashOrmMigrationManager::dry_run](crate::migration::CrashOrmMigrationManager::dry_run).
+pub trait RecordingDatabaseConnection: DatabaseConnection + Send + Sized {
+    /// Creates a connection in capture mode sharing the client of this connection.
+    ///
+    /// SELECT statements are executed as usual, all other statements are only recorded and return an empty result,
+    /// see [here](crate::connection#capture-mode) for the exact rules.
+    /// Retrieve the recorded statements with [take_recorded_statements](Self::take_recorded_statements).
+    fn recording(&self) -> Self;
+
+    /// Returns all statements recorded since the last call and clears them.
+    ///
+    /// Always returns an empty vector if this connection is not in capture mode.
+    fn take_recorded_statements(&self) -> Vec<RecordedStatement>;
+}
+
+/// A statement recorded by a connection in capture mode.
+#[derive(Clone, Debug)]
+pub struct RecordedStatement {
+    /// The raw SQL statement
+    pub statement: String,
+    /// Debug representation of the parameters of the statement
+    pub params: Vec<String>,
+}
+
+impl Display for RecordedStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{};", self.statement.trim_end().trim_end_matches(';'))?;
+
+        if !self.params.is_empty() {
+            let params = self.params.iter()
+                .enumerate()
+                .map(|(index, param)| format!("${} = {}", index + 1, param))
+                .collect::<Vec<String>>();
+            write!(f, " -- {}", params.join(", "))?;
+        }
+
+        Ok(())
+    }
 }
 
 /// The default, simple implementation of the [DatabaseConnection] trait.
 pub struct CrashOrmDatabaseConnection {
-    client: Client,
+    client: Arc<Client>,
+    recorder: Option<Mutex<Vec<RecordedStatement>>>,
 }
 
 impl CrashOrmDatabaseConnection {
@@ -61,7 +170,33 @@ impl CrashOrmDatabaseConnection {
             }
         });
 
-        Ok(Self { client })
+        Ok(Self {
+            client: Arc::new(client),
+            recorder: None,
+        })
+    }
+
+    /// Records the statement if this connection is in capture mode.
+    ///
+    /// Returns true if the statement was recorded and must not be executed.
+    fn record(&self, statement: &str, params: &[&(dyn ToSql + Sync)]) -> bool {
+        self.recorder.is_some() && !is_read_only(statement) && self.record_always(statement, params)
+    }
+
+    /// Records the statement if this connection is in capture mode, even if it only reads.
+    ///
+    /// Returns true if the statement was recorded and must not be executed.
+    fn record_always(&self, statement: &str, params: &[&(dyn ToSql + Sync)]) -> bool {
+        let Some(ref recorder) = self.recorder else {
+            return false;
+        };
+
+        recorder.lock().unwrap().push(RecordedStatement {
+            statement: statement.to_string(),
+            params: params.iter().map(|param| format!("{:?}", param)).collect(),
+        });
+
+        true
     }
 
     #[cfg(test)]
@@ -83,6 +218,23 @@ impl CrashOrmDatabaseConnection {
     }
 }
 
+/// Statements executed directly on the underlying [Client] are not recorded.
+impl RecordingDatabaseConnection for CrashOrmDatabaseConnection {
+    fn recording(&self) -> CrashOrmDatabaseConnection {
+        Self {
+            client: self.client.clone(),
+            recorder: Some(Mutex::new(vec![])),
+        }
+    }
+
+    fn take_recorded_statements(&self) -> Vec<RecordedStatement> {
+        match self.recorder {
+            Some(ref recorder) => std::mem::take(&mut *recorder.lock().unwrap()),
+            None => vec![],
+        }
+    }
+}
+
 impl Deref for CrashOrmDatabaseConnection {
     type Target = Client;
 
@@ -101,7 +253,7 @@ macro_rules! impl_database_connection {
             ) -> crate::Result<Option<Row>> {
                 self.query_opt(statement, params)
                     .await
-                    .map_err(|e| e.into())
+                    .map_err(|e| crate::Error::from_postgres(e, statement))
             }
 
             async fn query_many(
@@ -109,7 +261,7 @@ macro_rules! impl_database_connection {
                 statement: &str,
                 params: &[&(dyn ToSql + Sync)],
             ) -> crate::Result<Vec<Row>> {
-                self.query(statement, params).await.map_err(|e| e.into())
+                self.query(statement, params).await.map_err(|e| crate::Error::from_postgres(e, statement))
             }
 
             async fn execute_query(
@@ -117,14 +269,141 @@ macro_rules! impl_database_connection {
                 statement: &str,
                 params: &[&(dyn ToSql + Sync)],
             ) -> crate::Result<u64> {
-                self.execute(statement, params).await.map_err(|e| e.into())
+                self.execute(statement, params).await.map_err(|e| crate::Error::from_postgres(e, statement))
+            }
+
+            async fn execute_batch(&self, statements: &str) -> crate::Result<()> {
+                self.batch_execute(statements).await.map_err(|e| crate::Error::from_postgres(e, statements))
             }
         }
     };
 }
 
-impl_database_connection!(CrashOrmDatabaseConnection);
 impl_database_connection!(Client);
+impl_database_connection!(Transaction<'_>);
+
+impl DatabaseConnection for CrashOrmDatabaseConnection {
+    async fn query_single(
+        &self,
+        statement: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> crate::Result<Option<Row>> {
+        if self.record(statement, params) {
+            return Ok(None);
+        }
+
+        self.client.query_single(statement, params).await
+    }
+
+    async fn query_many(
+        &self,
+        statement: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> crate::Result<Vec<Row>> {
+        if self.record(statement, params) {
+            return Ok(vec![]);
+        }
+
+        self.client.query_many(statement, params).await
+    }
+
+    async fn execute_query(
+        &self,
+        statement: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> crate::Result<u64> {
+        if self.record(statement, params) {
+            return Ok(0);
+        }
+
+        self.client.execute_query(statement, params).await
+    }
+
+    async fn execute_batch(&self, statements: &str) -> crate::Result<()> {
+        // Scripts may contain anything, so they are never executed in capture mode
+        if self.record_always(statements, &[]) {
+            return Ok(());
+        }
+
+        self.client.execute_batch(statements).await
+    }
+
+    fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+}
+
+/// Returns whether the statement only reads and is executed in capture mode, see [here](crate::connection#capture-mode).
+///
+/// Anything that cannot be tokenized safely, like escape strings, dollar quotes or multiple statements, is not read-only.
+fn is_read_only(statement: &str) -> bool {
+    let mut words = vec![];
+    let mut chars = statement.chars().peekable();
+
+    // Words are collected in upper case, comments, quoted identifiers and string literals are skipped
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c => break,
+                        // A backslash may escape the quote in E'...' strings
+                        Some('\\') | None => return false,
+                        Some(_) => {},
+                    }
+                }
+            },
+            '-' if chars.next_if_eq(&'-').is_some() => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            },
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let mut depth = 1;
+                while depth > 0 {
+                    match chars.next() {
+                        Some('*') if chars.next_if_eq(&'/').is_some() => depth -= 1,
+                        Some('/') if chars.next_if_eq(&'*').is_some() => depth += 1,
+                        Some(_) => {},
+                        None => return false,
+                    }
+                }
+            },
+            ';' => return false,
+            // Parameters like $1 are allowed, dollar quoted strings are not
+            '$' => {
+                if chars.next_if(char::is_ascii_digit).is_none() {
+                    return false;
+                }
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+            },
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = c.to_ascii_uppercase().to_string();
+                while let Some(next) = chars.next_if(|next| next.is_alphanumeric() || *next == '_' || *next == '$') {
+                    word.push(next.to_ascii_uppercase());
+                }
+                words.push(word);
+            },
+            _ => {},
+        }
+    }
+
+    if !words.first().is_some_and(|first| matches!(&**first, "SELECT" | "WITH" | "VALUES" | "TABLE" | "SHOW")) {
+        return false;
+    }
+
+    words.iter().enumerate().all(|(index, word)| match &**word {
+        // FOR UPDATE and FOR NO KEY UPDATE only lock the selected rows
+        "UPDATE" => index > 0 && matches!(&*words[index - 1], "FOR" | "KEY"),
+        "NEXTVAL" | "SETVAL" => false,
+        // Words that are no keywords are identifiers or function names
+        word => KEYWORDS
+            .binary_search_by(|(keyword, _)| (*keyword).cmp(word))
+            .map_or(true, |index| KEYWORDS[index].1),
+    })
+}
 
 impl<T: DatabaseConnection + Send> DatabaseConnection for Arc<T> {
     async fn query_single(
@@ -150,6 +429,14 @@ impl<T: DatabaseConnection + Send> DatabaseConnection for Arc<T> {
     ) -> crate::Result<u64> {
         self.deref().execute_query(statement, params).await
     }
+
+    async fn execute_batch(&self, statements: &str) -> crate::Result<()> {
+        self.deref().execute_batch(statements).await
+    }
+
+    fn is_recording(&self) -> bool {
+        self.deref().is_recording()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,465 @@
+/// All keywords of Postgres, sorted, and whether they are allowed in a statement executed in capture mode.
+///
+/// Generated with `SELECT upper(word) FROM pg_get_keywords() ORDER BY 1`.
+pub(super) const KEYWORDS: [(&str, bool); 460] = [
+    ("ABORT", false),
+    ("ABSOLUTE", true),
+    ("ACCESS", true),
+    ("ACTION", true),
+    ("ADD", true),
+    ("ADMIN", true),
+    ("AFTER", true),
+    ("AGGREGATE", true),
+    ("ALL", true),
+    ("ALSO", true),
+    ("ALTER", false),
+    ("ALWAYS", true),
+    ("ANALYSE", false),
+    ("ANALYZE", false),
+    ("AND", true),
+    ("ANY", true),
+    ("ARRAY", true),
+    ("AS", true),
+    ("ASC", true),
+    ("ASENSITIVE", true),
+    ("ASSERTION", true),
+    ("ASSIGNMENT", true),
+    ("ASYMMETRIC", true),
+    ("AT", true),
+    ("ATOMIC", true),
+    ("ATTACH", false),
+    ("ATTRIBUTE", true),
+    ("AUTHORIZATION", true),
+    ("BACKWARD", true),
+    ("BEFORE", true),
+    ("BEGIN", false),
+    ("BETWEEN", true),
+    ("BIGINT", true),
+    ("BINARY", true),
+    ("BIT", true),
+    ("BOOLEAN", true),
+    ("BOTH", true),
+    ("BREADTH", true),
+    ("BY", true),
+    ("CACHE", true),
+    ("CALL", false),
+    ("CALLED", true),
+    ("CASCADE", true),
+    ("CASCADED", true),
+    ("CASE", true),
+    ("CAST", true),
+    ("CATALOG", true),
+    ("CHAIN", true),
+    ("CHAR", true),
+    ("CHARACTER", true),
+    ("CHARACTERISTICS", true),
+    ("CHECK", true),
+    ("CHECKPOINT", false),
+    ("CLASS", true),
+    ("CLOSE", false),
+    ("CLUSTER", false),
+    ("COALESCE", true),
+    ("COLLATE", true),
+    ("COLLATION", true),
+    ("COLUMN", true),
+    ("COLUMNS", true),
+    ("COMMENT", false),
+    ("COMMENTS", true),
+    ("COMMIT", false),
+    ("COMMITTED", true),
+    ("COMPRESSION", true),
+    ("CONCURRENTLY", true),
+    ("CONFIGURATION", true),
+    ("CONFLICT", true),
+    ("CONNECTION", true),
+    ("CONSTRAINT", true),
+    ("CONSTRAINTS", true),
+    ("CONTENT", true),
+    ("CONTINUE", true),
+    ("CONVERSION", true),
+    ("COPY", false),
+    ("COST", true),
+    ("CREATE", false),
+    ("CROSS", true),
+    ("CSV", true),
+    ("CUBE", true),
+    ("CURRENT", true),
+    ("CURRENT_CATALOG", true),
+    ("CURRENT_DATE", true),
+    ("CURRENT_ROLE", true),
+    ("CURRENT_SCHEMA", true),
+    ("CURRENT_TIME", true),
+    ("CURRENT_TIMESTAMP", true),
+    ("CURRENT_USER", true),
+    ("CURSOR", true),
+    ("CYCLE", true),
+    ("DATA", true),
+    ("DATABASE", true),
+    ("DAY", true),
+    ("DEALLOCATE", false),
+    ("DEC", true),
+    ("DECIMAL", true),
+    ("DECLARE", false),
+    ("DEFAULT", true),
+    ("DEFAULTS", true),
+    ("DEFERRABLE", true),
+    ("DEFERRED", true),
+    ("DEFINER", true),
+    ("DELETE", false),
+    ("DELIMITER", true),
+    ("DELIMITERS", true),
+    ("DEPENDS", true),
+    ("DEPTH", true),
+    ("DESC", true),
+    ("DETACH", false),
+    ("DICTIONARY", true),
+    ("DISABLE", true),
+    ("DISCARD", false),
+    ("DISTINCT", true),
+    ("DO", false),
+    ("DOCUMENT", true),
+    ("DOMAIN", true),
+    ("DOUBLE", true),
+    ("DROP", false),
+    ("EACH", true),
+    ("ELSE", true),
+    ("ENABLE", true),
+    ("ENCODING", true),
+    ("ENCRYPTED", true),
+    ("END", true),
+    ("ENUM", true),
+    ("ESCAPE", true),
+    ("EVENT", true),
+    ("EXCEPT", true),
+    ("EXCLUDE", true),
+    ("EXCLUDING", true),
+    ("EXCLUSIVE", true),
+    ("EXECUTE", false),
+    ("EXISTS", true),
+    ("EXPLAIN", false),
+    ("EXPRESSION", true),
+    ("EXTENSION", true),
+    ("EXTERNAL", true),
+    ("EXTRACT", true),
+    ("FALSE", true),
+    ("FAMILY", true),
+    ("FETCH", true),
+    ("FILTER", true),
+    ("FINALIZE", true),
+    ("FIRST", true),
+    ("FLOAT", true),
+    ("FOLLOWING", true),
+    ("FOR", true),
+    ("FORCE", true),
+    ("FOREIGN", true),
+    ("FORWARD", true),
+    ("FREEZE", true),
+    ("FROM", true),
+    ("FULL", true),
+    ("FUNCTION", true),
+    ("FUNCTIONS", true),
+    ("GENERATED", true),
+    ("GLOBAL", true),
+    ("GRANT", false),
+    ("GRANTED", true),
+    ("GREATEST", true),
+    ("GROUP", true),
+    ("GROUPING", true),
+    ("GROUPS", true),
+    ("HANDLER", true),
+    ("HAVING", true),
+    ("HEADER", true),
+    ("HOLD", true),
+    ("HOUR", true),
+    ("IDENTITY", true),
+    ("IF", true),
+    ("ILIKE", true),
+    ("IMMEDIATE", true),
+    ("IMMUTABLE", true),
+    ("IMPLICIT", true),
+    ("IMPORT", false),
+    ("IN", true),
+    ("INCLUDE", true),
+    ("INCLUDING", true),
+    ("INCREMENT", true),
+    ("INDEX", true),
+    ("INDEXES", true),
+    ("INHERIT", true),
+    ("INHERITS", true),
+    ("INITIALLY", true),
+    ("INLINE", true),
+    ("INNER", true),
+    ("INOUT", true),
+    ("INPUT", true),
+    ("INSENSITIVE", true),
+    ("INSERT", false),
+    ("INSTEAD", true),
+    ("INT", true),
+    ("INTEGER", true),
+    ("INTERSECT", true),
+    ("INTERVAL", true),
+    ("INTO", false),
+    ("INVOKER", true),
+    ("IS", true),
+    ("ISNULL", true),
+    ("ISOLATION", true),
+    ("JOIN", true),
+    ("KEY", true),
+    ("LABEL", true),
+    ("LANGUAGE", true),
+    ("LARGE", true),
+    ("LAST", true),
+    ("LATERAL", true),
+    ("LEADING", true),
+    ("LEAKPROOF", true),
+    ("LEAST", true),
+    ("LEFT", true),
+    ("LEVEL", true),
+    ("LIKE", true),
+    ("LIMIT", true),
+    ("LISTEN", false),
+    ("LOAD", false),
+    ("LOCAL", true),
+    ("LOCALTIME", true),
+    ("LOCALTIMESTAMP", true),
+    ("LOCATION", true),
+    ("LOCK", false),
+    ("LOCKED", true),
+    ("LOGGED", true),
+    ("MAPPING", true),
+    ("MATCH", true),
+    ("MATCHED", true),
+    ("MATERIALIZED", true),
+    ("MAXVALUE", true),
+    ("MERGE", false),
+    ("METHOD", true),
+    ("MINUTE", true),
+    ("MINVALUE", true),
+    ("MODE", true),
+    ("MONTH", true),
+    ("MOVE", false),
+    ("NAME", true),
+    ("NAMES", true),
+    ("NATIONAL", true),
+    ("NATURAL", true),
+    ("NCHAR", true),
+    ("NEW", true),
+    ("NEXT", true),
+    ("NFC", true),
+    ("NFD", true),
+    ("NFKC", true),
+    ("NFKD", true),
+    ("NO", true),
+    ("NONE", true),
+    ("NORMALIZE", true),
+    ("NORMALIZED", true),
+    ("NOT", true),
+    ("NOTHING", true),
+    ("NOTIFY", false),
+    ("NOTNULL", true),
+    ("NOWAIT", true),
+    ("NULL", true),
+    ("NULLIF", true),
+    ("NULLS", true),
+    ("NUMERIC", true),
+    ("OBJECT", true),
+    ("OF", true),
+    ("OFF", true),
+    ("OFFSET", true),
+    ("OIDS", true),
+    ("OLD", true),
+    ("ON", true),
+    ("ONLY", true),
+    ("OPERATOR", true),
+    ("OPTION", true),
+    ("OPTIONS", true),
+    ("OR", true),
+    ("ORDER", true),
+    ("ORDINALITY", true),
+    ("OTHERS", true),
+    ("OUT", true),
+    ("OUTER", true),
+    ("OVER", true),
+    ("OVERLAPS", true),
+    ("OVERLAY", true),
+    ("OVERRIDING", true),
+    ("OWNED", true),
+    ("OWNER", true),
+    ("PARALLEL", true),
+    ("PARAMETER", true),
+    ("PARSER", true),
+    ("PARTIAL", true),
+    ("PARTITION", true),
+    ("PASSING", true),
+    ("PASSWORD", true),
+    ("PLACING", true),
+    ("PLANS", true),
+    ("POLICY", true),
+    ("POSITION", true),
+    ("PRECEDING", true),
+    ("PRECISION", true),
+    ("PREPARE", false),
+    ("PREPARED", true),
+    ("PRESERVE", true),
+    ("PRIMARY", true),
+    ("PRIOR", true),
+    ("PRIVILEGES", true),
+    ("PROCEDURAL", true),
+    ("PROCEDURE", true),
+    ("PROCEDURES", true),
+    ("PROGRAM", true),
+    ("PUBLICATION", true),
+    ("QUOTE", true),
+    ("RANGE", true),
+    ("READ", true),
+    ("REAL", true),
+    ("REASSIGN", false),
+    ("RECHECK", true),
+    ("RECURSIVE", true),
+    ("REF", true),
+    ("REFERENCES", true),
+    ("REFERENCING", true),
+    ("REFRESH", false),
+    ("REINDEX", false),
+    ("RELATIVE", true),
+    ("RELEASE", false),
+    ("RENAME", false),
+    ("REPEATABLE", true),
+    ("REPLACE", true),
+    ("REPLICA", true),
+    ("RESET", false),
+    ("RESTART", true),
+    ("RESTRICT", true),
+    ("RETURN", true),
+    ("RETURNING", true),
+    ("RETURNS", true),
+    ("REVOKE", false),
+    ("RIGHT", true),
+    ("ROLE", true),
+    ("ROLLBACK", false),
+    ("ROLLUP", true),
+    ("ROUTINE", true),
+    ("ROUTINES", true),
+    ("ROW", true),
+    ("ROWS", true),
+    ("RULE", true),
+    ("SAVEPOINT", false),
+    ("SCHEMA", true),
+    ("SCHEMAS", true),
+    ("SCROLL", true),
+    ("SEARCH", true),
+    ("SECOND", true),
+    ("SECURITY", false),
+    ("SELECT", true),
+    ("SEQUENCE", true),
+    ("SEQUENCES", true),
+    ("SERIALIZABLE", true),
+    ("SERVER", true),
+    ("SESSION", true),
+    ("SESSION_USER", true),
+    ("SET", false),
+    ("SETOF", true),
+    ("SETS", true),
+    ("SHARE", true),
+    ("SHOW", true),
+    ("SIMILAR", true),
+    ("SIMPLE", true),
+    ("SKIP", true),
+    ("SMALLINT", true),
+    ("SNAPSHOT", true),
+    ("SOME", true),
+    ("SQL", true),
+    ("STABLE", true),
+    ("STANDALONE", true),
+    ("START", false),
+    ("STATEMENT", true),
+    ("STATISTICS", true),
+    ("STDIN", true),
+    ("STDOUT", true),
+    ("STORAGE", true),
+    ("STORED", true),
+    ("STRICT", true),
+    ("STRIP", true),
+    ("SUBSCRIPTION", true),
+    ("SUBSTRING", true),
+    ("SUPPORT", true),
+    ("SYMMETRIC", true),
+    ("SYSID", true),
+    ("SYSTEM", true),
+    ("TABLE", true),
+    ("TABLES", true),
+    ("TABLESAMPLE", true),
+    ("TABLESPACE", true),
+    ("TEMP", true),
+    ("TEMPLATE", true),
+    ("TEMPORARY", true),
+    ("TEXT", true),
+    ("THEN", true),
+    ("TIES", true),
+    ("TIME", true),
+    ("TIMESTAMP", true),
+    ("TO", true),
+    ("TRAILING", true),
+    ("TRANSACTION", true),
+    ("TRANSFORM", true),
+    ("TREAT", true),
+    ("TRIGGER", true),
+    ("TRIM", true),
+    ("TRUE", true),
+    ("TRUNCATE", false),
+    ("TRUSTED", true),
+    ("TYPE", true),
+    ("TYPES", true),
+    ("UESCAPE", true),
+    ("UNBOUNDED", true),
+    ("UNCOMMITTED", true),
+    ("UNENCRYPTED", true),
+    ("UNION", true),
+    ("UNIQUE", true),
+    ("UNKNOWN", true),
+    ("UNLISTEN", false),
+    ("UNLOGGED", true),
+    ("UNTIL", true),
+    ("UPDATE", false),
+    ("USER", true),
+    ("USING", true),
+    ("VACUUM", false),
+    ("VALID", true),
+    ("VALIDATE", true),
+    ("VALIDATOR", true),
+    ("VALUE", true),
+    ("VALUES", true),
+    ("VARCHAR", true),
+    ("VARIADIC", true),
+    ("VARYING", true),
+    ("VERBOSE", true),
+    ("VERSION", true),
+    ("VIEW", true),
+    ("VIEWS", true),
+    ("VOLATILE", true),
+    ("WHEN", true),
+    ("WHERE", true),
+    ("WHITESPACE", true),
+    ("WINDOW", true),
+    ("WITH", true),
+    ("WITHIN", true),
+    ("WITHOUT", true),
+    ("WORK", true),
+    ("WRAPPER", true),
+    ("WRITE", true),
+    ("XML", true),
+    ("XMLATTRIBUTES", true),
+    ("XMLCONCAT", true),
+    ("XMLELEMENT", true),
+    ("XMLEXISTS", true),
+    ("XMLFOREST", true),
+    ("XMLNAMESPACES", true),
+    ("XMLPARSE", true),
+    ("XMLPI", true),
+    ("XMLROOT", true),
+    ("XMLSERIALIZE", true),
+    ("XMLTABLE", true),
+    ("YEAR", true),
+    ("YES", true),
+    ("ZONE", true),
+];
@@ -0,0 +1,255 @@
+//! # Common table expressions
+//! A query is added as common table expression with [Query::with_cte], translated to `WITH name AS (...)`.
+//! The rows of the expression are selected with a [Cte] of the same name, as long as they have the columns of the entity.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Customer {
+//! #    id: u32,
+//! # }
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Invoice {
+//! #    id: u32,
+//! #    customer: ManyToOne<Customer, u32>,
+//! #    paid: bool,
+//! # }
+//!
+//! // WITH open AS (SELECT * FROM public.invoice WHERE paid = $1) SELECT * FROM public.customer
+//! //     WHERE id IN (SELECT customer FROM open)
+//! let open = Cte::<Invoice>::new("open");
+//! let query = Customer::query()
+//!     .with_cte("open", Invoice::query().condition(InvoiceColumn::PAID.equals(false)))
+//!     .condition(CustomerColumn::ID.in_query(open.select_query::<SingleResult<u32>>(&[&InvoiceColumn::CUSTOMER_PRIMARY])));
+//! ```
+//!
+//! ## Recursive
+//! [Cte::recursive] creates a `WITH RECURSIVE` expression from two conditions:
+//! - the anchor selects the starting rows
+//! - the step selects the rows following a row of the expression, which is referenced with [Cte::column]
+//!
+//! Each row has a `depth` column, 0 for the starting rows. It is selected with [Cte::depth]
+//! or mapped together with the entity with [WithDepth].
+//! Entities with a column named `depth` can't be used in a recursive expression.
+//!
+//! For a self-referential [ManyToOne](crate::prelude::ManyToOne), [descendants](Cte::descendants)
+//! and [ancestors](Cte::ancestors) walk the tree.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleCategory {
+//!     id: u32,
+//!     name: String,
+//!     parent: Option<ManyToOne<ExampleCategory, u32>>,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # ExampleCategory::create_table_if_not_exists(&conn).await.unwrap();
+//! let tree = Cte::<ExampleCategory>::new("tree");
+//!
+//! // Same as tree.descendants(&ExampleCategoryColumn::ID, &ExampleCategoryColumn::PARENT_PRIMARY, 1)
+//! let descendants: Vec<WithDepth<ExampleCategory>> = tree
+//!     .recursive(
+//!         ExampleCategoryColumn::ID.equals(1),
+//!         ExampleCategoryColumn::PARENT_PRIMARY.equals(tree.column(&ExampleCategoryColumn::ID)),
+//!     )
+//!     .query()
+//!     .order(&tree.depth(), OrderDirection::ASC)
+//!     .fetch(&conn).await.unwrap();
+//! # ExampleCategory::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
+
+use std::marker::PhantomData;
+
+use postgres::Row;
+
+use crate::prelude::{BoxedSql, ColumnType, Entity, EntityColumn, IntoSql, Query, QueryCondition, ResultMapping, SelectQueryType, UntypedColumn, VirtualColumn};
+use crate::query::FromClause;
+use crate::result_mapping::try_get_named_column;
+
+/// Named common table expression with the columns of the entity `T`, see [here](crate::cte).
+pub struct Cte<T: Entity> {
+    name: String,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Entity> Cte<T> {
+    /// References the common table expression with the name
+    pub fn new(name: &str) -> Cte<T> {
+        Self {
+            name: name.to_string(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Name of the common table expression
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// References a column of the common table expression, e.g. `tree.id`
+    pub fn column<X: ColumnType>(&self, column: &EntityColumn<X, T>) -> VirtualColumn<X, T> {
+        VirtualColumn::new(BoxedSql::new(format!("{}.{}", self.name, column.get_sql().sql), vec![]))
+    }
+
+    /// Depth of the row in a recursive common table expression, 0 for the rows of the anchor
+    pub fn depth(&self) -> VirtualColumn<i32, T> {
+        VirtualColumn::new(BoxedSql::new(format!("{}.depth", self.name), vec![]))
+    }
+
+    /// Query selecting all rows of the common table expression.
+    ///
+    /// The expression itself is added with [Query::with_cte].
+    pub fn query<R: ResultMapping>(&self) -> Query<T, R, SelectQueryType> {
+        Query::new(BoxedSql::new(String::from("SELECT *"), vec![])).from(FromClause::cte(&self.name))
+    }
+
+    /// Query selecting the columns of the common table expression.
+    ///
+    /// The expression itself is added with [Query::with_cte].
+    pub fn select_query<R: ResultMapping>(&self, columns: &[&dyn UntypedColumn<T>]) -> Query<T, R, SelectQueryType> {
+        Query::new(select_columns(columns)).from(FromClause::cte(&self.name)).selecting(columns)
+    }
+
+    /// Creates a recursive common table expression with this name.
+    ///
+    /// The anchor selects the starting rows, the step selects the rows following a row of the expression.
+    pub fn recursive(&self, anchor: QueryCondition<T>, step: QueryCondition<T>) -> RecursiveCte<T> {
+        RecursiveCte {
+            name: self.name.clone(),
+            anchor,
+            step,
+            max_depth: None,
+        }
+    }
+
+    /// Walks a self-referential relation down from the root, e.g. all subcategories of a category.
+    ///
+    /// The root is included with depth 0, its children have depth 1 and so on.
+    pub fn descendants<P: ColumnType>(&self, id: &EntityColumn<P, T>, parent: &EntityColumn<P, T>, root: impl IntoSql<P>) -> RecursiveCte<T> {
+        self.recursive(self.anchor(id, root), self.step(parent, id))
+    }
+
+    /// Walks a self-referential relation up from the start, e.g. all managers of an employee.
+    ///
+    /// The start is included with depth 0, its parent has depth 1 and so on.
+    pub fn ancestors<P: ColumnType>(&self, id: &EntityColumn<P, T>, parent: &EntityColumn<P, T>, start: impl IntoSql<P>) -> RecursiveCte<T> {
+        self.recursive(self.anchor(id, start), self.step(id, parent))
+    }
+
+    fn anchor<P: ColumnType>(&self, id: &EntityColumn<P, T>, value: impl IntoSql<P>) -> QueryCondition<T> {
+        let mut boxed = id.get_sql();
+        let value = value.into_boxed_sql();
+        boxed.modify(|v| format!("{} = {}", v, value.sql));
+        boxed.values.extend(value.values);
+        QueryCondition::new(boxed)
+    }
+
+    fn step<P: ColumnType>(&self, column: &EntityColumn<P, T>, previous: &EntityColumn<P, T>) -> QueryCondition<T> {
+        let mut boxed = column.get_sql();
+        let previous = self.column(previous).get_sql();
+        boxed.modify(|v| format!("{} = {}", v, previous.sql));
+        QueryCondition::new(boxed)
+    }
+}
+
+/// Recursive common table expression, created with [Cte::recursive].
+pub struct RecursiveCte<T: Entity> {
+    name: String,
+    anchor: QueryCondition<T>,
+    step: QueryCondition<T>,
+    max_depth: Option<i32>,
+}
+
+impl<T: Entity> RecursiveCte<T> {
+    /// Stops the recursion at the depth, e.g. to guard against cycles in the data
+    pub fn max_depth(mut self, max_depth: i32) -> RecursiveCte<T> {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Query selecting all rows of this expression, mapped into the entity, [WithDepth] or any other [ResultMapping].
+    pub fn query<R: ResultMapping>(self) -> Query<T, R, SelectQueryType> {
+        let name = self.name.clone();
+        Cte::new(&name).query().with_recursive_cte(self)
+    }
+
+    /// Query selecting the columns of this expression
+    pub fn select_query<R: ResultMapping>(self, columns: &[&dyn UntypedColumn<T>]) -> Query<T, R, SelectQueryType> {
+        Query::new(select_columns(columns)).from(FromClause::cte(&self.name)).selecting(columns).with_recursive_cte(self)
+    }
+
+    /// Converts this expression into `name AS (anchor UNION ALL step)`
+    pub(crate) fn into_boxed_sql(self) -> BoxedSql {
+        let name = self.name;
+        let mut boxed = self.anchor.boxed;
+        boxed.modify(|v| format!("SELECT *, 0 AS depth FROM public.{} WHERE {}", T::TABLE_NAME, v));
+
+        let limit = match self.max_depth {
+            Some(max_depth) => format!(" WHERE {}.depth < {}", name, max_depth),
+            None => String::new(),
+        };
+
+        // The step is evaluated in a lateral subquery, so the columns of the entity are not ambiguous
+        boxed.sql.push_str(&format!(
+            " UNION ALL SELECT {table}.*, {name}.depth + 1 FROM {name}, LATERAL (SELECT * FROM public.{table} WHERE {}) {table}{}",
+            self.step.boxed.sql, limit, table = T::TABLE_NAME,
+        ));
+        boxed.values.extend(self.step.boxed.values);
+
+        boxed.modify(|v| format!("{} AS ({})", name, v));
+        boxed
+    }
+}
+
+/// Maps a row of a recursive common table expression into `T` and its depth
+#[derive(Debug)]
+pub struct WithDepth<T: ResultMapping> {
+    /// Mapped row
+    pub entity: T,
+    /// Depth of the row, 0 for the rows of the anchor
+    pub depth: i32,
+}
+
+impl<T: ResultMapping> ResultMapping for WithDepth<T> {
+    fn from_row(row: Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        let depth = try_get_named_column(&row, "depth")?;
+
+        Ok(Self {
+            entity: T::from_row(row)?,
+            depth,
+        })
+    }
+
+    fn from_row_ref(row: &Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Ok(Self {
+            entity: T::from_row_ref(row)?,
+            depth: try_get_named_column(row, "depth")?,
+        })
+    }
+}
+
+fn select_columns<T: Entity>(columns: &[&dyn UntypedColumn<T>]) -> BoxedSql {
+    let mut sql = vec![];
+    let mut values = vec![];
+
+    for column in columns {
+        let column = column.get_select_sql();
+        sql.push(column.sql);
+        values.extend(column.values);
+    }
+
+    BoxedSql::new(format!("SELECT {}", sql.join(",")), values)
+}
@@ -0,0 +1,175 @@
+//! # Enums
+//! Fieldless Rust enums can be stored as Postgres enum types by deriving [DbEnum](crate::derive::DbEnum).
+//!
+//! The derive implements [ToSql](postgres::types::ToSql) and [FromSql](postgres::types::FromSql), so the enum can be used as property of an entity.
+//! [Debug] and [Clone] are required as well, with the feature `serialize` also Serialize and Deserialize of serde.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(DbEnum, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+//! enum ExampleStatus {
+//!     Active,
+//!     #[db_enum(name = "disabled")]
+//!     Inactive,
+//! }
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleEnumEntity {
+//!     id: u32,
+//!     status: ExampleStatus,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! // Creates the type example_status if it does not exist, then the table.
+//! ExampleEnumEntity::create_table_if_not_exists(&conn).await.unwrap();
+//!
+//! let active = ExampleEnumEntity::query()
+//!     .condition(ExampleEnumEntityColumn::STATUS.equals(ExampleStatus::Active))
+//!     .fetch(&conn).await.unwrap();
+//! # ExampleEnumEntity::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
+//!
+//! The name of the type is the snake case name of the enum, the labels are the snake case names of the variants.
+//! Both can be changed with `#[db_enum(name = "...")]`.
+//!
+//! The [Schema](crate::schema::Schema) derive creates the type before the table if it does not exist yet.
+//! The derive also implements [SqlType] and [OrderedSqlType](crate::prelude::OrderedSqlType),
+//! so columns of an enum support the equality, IN and comparison conditions.
+//! Postgres orders the labels of an enum in their declaration order.
+//!
+//! ## Migrations
+//! After adding a variant, add the new label to the type in a migration with [DbEnum::add_missing_values]
+//! or [DbEnum::add_value]. Postgres does not support removing labels from an enum type.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use postgres::types::private::BytesMut;
+use postgres::types::{IsNull, Type};
+
+use crate::prelude::{DatabaseConnection, SqlType};
+
+/// Trait for Rust enums stored as Postgres enum type, see [here](crate::db_enum).
+///
+/// This trait should be derived with [DbEnum](crate::derive::DbEnum).
+/// The name of the type is [SQL_TYPE](SqlType::SQL_TYPE).
+#[async_trait]
+pub trait DbEnum: SqlType {
+    /// Labels of all variants in declaration order
+    const VARIANTS: &'static [&'static str];
+
+    /// Returns the label of this variant.
+    fn to_label(&self) -> &'static str;
+
+    /// Returns the variant of a label.
+    fn from_label(label: &str) -> Option<Self>;
+
+    /// Creates the enum type with all variants.
+    ///
+    /// This will fail, if the type already exists.
+    async fn create_type(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        let labels = Self::VARIANTS.iter().map(|v| quote_label(v)).collect::<Vec<String>>().join(",");
+        connection.execute_query(&format!("CREATE TYPE {} AS ENUM ({})", Self::SQL_TYPE, labels), &[]).await?;
+
+        Ok(())
+    }
+
+    /// Creates the enum type if it doesn't exist.
+    async fn create_type_if_not_exists(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        if !Self::type_exists(connection).await? {
+            Self::create_type(connection).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Drop the enum type if it exists.
+    ///
+    /// This will fail, if the type is still used by a table.
+    async fn drop_type(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        connection.execute_query(&format!("DROP TYPE IF EXISTS {}", Self::SQL_TYPE), &[]).await?;
+
+        Ok(())
+    }
+
+    /// Check whether the enum type exists or not
+    async fn type_exists(connection: &impl DatabaseConnection) -> crate::Result<bool> {
+        let row = connection.query_single(
+            "SELECT EXISTS(SELECT FROM pg_type WHERE typname = $1 AND typtype = 'e')",
+            &[&Self::SQL_TYPE],
+        ).await?;
+
+        Ok(row.is_some_and(|row| row.get(0)))
+    }
+
+    /// Adds a label to the enum type, if it doesn't exist yet.
+    ///
+    /// The label is added after all existing labels.
+    async fn add_value(connection: &impl DatabaseConnection, label: &str) -> crate::Result<()> {
+        connection.execute_query(
+            &format!("ALTER TYPE {} ADD VALUE IF NOT EXISTS {}", Self::SQL_TYPE, quote_label(label)),
+            &[],
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Adds all labels of [VARIANTS](Self::VARIANTS) missing in the enum type.
+    ///
+    /// Missing labels are inserted after the label of the previous variant to keep the declaration order,
+    /// a missing first variant is inserted before all existing labels.
+    async fn add_missing_values(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        let rows = connection.query_many(
+            "SELECT e.enumlabel FROM pg_enum e JOIN pg_type t ON t.oid = e.enumtypid WHERE t.typname = $1 ORDER BY e.enumsortorder",
+            &[&Self::SQL_TYPE],
+        ).await?;
+        let existing = rows.iter().map(|row| row.get::<_, String>(0)).collect::<Vec<String>>();
+
+        for (index, label) in Self::VARIANTS.iter().enumerate() {
+            if existing.iter().any(|v| v == label) {
+                continue;
+            }
+
+            let position = match (index, existing.first()) {
+                (0, Some(first)) => format!(" BEFORE {}", quote_label(first)),
+                (0, None) => String::new(),
+                _ => format!(" AFTER {}", quote_label(Self::VARIANTS[index - 1])),
+            };
+
+            connection.execute_query(
+                &format!("ALTER TYPE {} ADD VALUE {}{}", Self::SQL_TYPE, quote_label(label), position),
+                &[],
+            ).await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn quote_label(label: &str) -> String {
+    format!("'{}'", label.replace('\'', "''"))
+}
+
+/// Implementation of [ToSql::to_sql](postgres::types::ToSql::to_sql) for a [DbEnum].
+#[doc(hidden)]
+pub fn db_enum_to_sql<E: DbEnum>(value: &E, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    out.extend_from_slice(value.to_label().as_bytes());
+    Ok(IsNull::No)
+}
+
+/// Implementation of [FromSql::from_sql](postgres::types::FromSql::from_sql) for a [DbEnum].
+#[doc(hidden)]
+pub fn db_enum_from_sql<E: DbEnum>(raw: &[u8]) -> Result<E, Box<dyn Error + Sync + Send>> {
+    let label = std::str::from_utf8(raw)?;
+    E::from_label(label).ok_or_else(|| format!("unknown label {} of enum {}", label, E::SQL_TYPE).into())
+}
+
+/// Implementation of `accepts` of [ToSql](postgres::types::ToSql) and [FromSql](postgres::types::FromSql) for a [DbEnum].
+#[doc(hidden)]
+pub fn db_enum_accepts<E: DbEnum>(ty: &Type) -> bool {
+    ty.name() == E::SQL_TYPE
+}
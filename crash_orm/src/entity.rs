@@ -208,6 +208,7 @@ use async_trait::async_trait;
 use tokio_postgres::types::ToSql;
 
 use crate::prelude::*;
+use crate::query::FromClause;
 use crate::result_mapping::ResultMapping;
 
 /// Trait implemented for all database entities.
@@ -248,23 +249,28 @@ pub trait Entity: ResultMapping + Send + Sync + Debug + 'static {
 
     /// Insert and set id
     ///
-    /// This sets the id in the entity
+    /// This sets the id in the entity.
+    /// In [capture mode](crate::connection#capture-mode) the insert is only recorded and the id is kept.
     async fn insert(&mut self, connection: &impl DatabaseConnection) -> Result<()>;
 
     /// Removes the entity from the database
+    ///
+    /// Returns [Error::NotFound](crate::Error::NotFound) if the entity does not exist in the database.
+    /// This check is skipped in [capture mode](crate::connection#capture-mode), as the statement is only recorded.
     async fn remove(&self, connection: &impl DatabaseConnection) -> Result<()>;
 
     /// Updates the entity in the database
+    ///
+    /// Returns [Error::NotFound](crate::Error::NotFound) if the entity does not exist in the database.
+    /// This check is skipped in [capture mode](crate::connection#capture-mode), as the statement is only recorded.
     async fn update(&self, connection: &impl DatabaseConnection) -> Result<()>;
 
     /// Creates a SELECT [Query] for this entity.
     ///
     /// See [Query] for more details on how to build a query.
     fn query() -> Query<Self, Self, SelectQueryType> where Self: Sized {
-        Query::new(BoxedSql::new(
-            format!("SELECT * FROM public.{}", Self::TABLE_NAME),
-            vec![],
-        ))
+        Query::new(BoxedSql::new(String::from("SELECT *"), vec![]))
+            .from(FromClause::table::<Self>())
     }
 
     /// Creates a DELETE [Query] for this entity.
@@ -277,29 +283,35 @@ pub trait Entity: ResultMapping + Send + Sync + Debug + 'static {
         ))
     }
 
+    /// Creates an UPDATE [Query] for this entity, the new values are added with [set](Query::set).
+    ///
+    /// See [Query] for more details on how to build a query.
+    fn update_query() -> Query<Self, (), UpdateQueryType> where Self: Sized {
+        Query::new(BoxedSql::new(
+            format!("UPDATE public.{}", Self::TABLE_NAME),
+            vec![],
+        ))
+    }
+
     /// Select specific columns ([EntityColumn] or [VirtualColumn]) from this entity.
     ///
     /// This returns a [SelectQuery]. See [SelectQuery] for more details.
     fn select_query<R: ResultMapping>(columns: &[&dyn UntypedColumn<Self>]) -> Query<Self, R, SelectQueryType> where Self: Sized {
-        let columns = columns
+        let selected = columns
             .iter()
-            .map(|v| v.get_sql())
+            .map(|v| v.get_select_sql())
             .collect::<Vec<BoxedSql>>();
         let mut query = vec![];
         let mut values = vec![];
-        let mut index = 1;
 
-        for column in columns {
-            let (new_query, new_values, next_index) = column.resolve(index);
-            query.push(new_query);
-            values.extend(new_values);
-            index = next_index;
+        for column in selected {
+            query.push(column.sql);
+            values.extend(column.values);
         }
 
-        Query::new(BoxedSql::new(
-            format!("SELECT {} FROM public.{}", query.join(","), Self::TABLE_NAME),
-            values,
-        ))
+        Query::new(BoxedSql::new(format!("SELECT {}", query.join(",")), values))
+            .from(FromClause::table::<Self>())
+            .selecting(columns)
     }
 }
 
@@ -4,7 +4,7 @@
 
 use std::marker::PhantomData;
 
-use crate::prelude::{BoxedSql, ColumnType, Entity};
+use crate::prelude::{BoxedSql, ColumnType, Entity, VirtualColumn};
 
 /// Struct holding information about a column of an entity.
 ///
@@ -32,4 +32,14 @@ impl<T: ColumnType, U: Entity> EntityColumn<T, U> {
     pub(crate) fn get_sql(&self) -> BoxedSql {
         BoxedSql::new(self.name.to_string(), vec![])
     }
+
+    /// References this column qualified with the table name, e.g. `customer.id`.
+    ///
+    /// This is used in correlated subqueries to reference a column of the outer query,
+    /// see [exists](crate::prelude::QueryCondition::exists).
+    /// If the subquery selects from the same entity, its table is aliased as `<table>_subquery`,
+    /// so this still references the outer query.
+    pub fn outer(&self) -> VirtualColumn<T, U> {
+        VirtualColumn::new(BoxedSql::new(format!("{}.{}", U::TABLE_NAME, self.name), vec![]))
+    }
 }
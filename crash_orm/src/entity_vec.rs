@@ -7,9 +7,7 @@ use crate::prelude::{ColumnType, CreateEntity, DatabaseConnection};
 
 /// Trait implementing useful functions for vectors of entities.
 pub trait EntityVec<P: ColumnType> {
-    /// Shortcut function to call [Entity::remove] on every entity in this vector.
-    ///
-    /// This will be a batch operation in the future.
+    /// Removes all entities in this vector with a single DELETE, binding the ids as one array parameter.
     fn remove_all(&self, connection: &impl DatabaseConnection) -> impl std::future::Future<Output = crate::Result<()>> + Send;
 }
 
@@ -23,16 +21,8 @@ impl<T: PrimaryKeyEntity<P>, P: ColumnType> EntityVec<P> for Vec<T> {
             .map(|v| v.get_primary())
             .collect::<Vec<P>>();
 
-        let query = format!(
-            "DELETE FROM {} WHERE id IN ({})",
-            T::TABLE_NAME,
-            (0..ids.len()).map(|index| format!("${}", index+1)).collect::<Vec<String>>().join(",")
-        );
-
-        connection.execute_query(&*query, slice_to_sql_iter(ids.as_slice())
-            .collect::<Vec<&(dyn ToSql + Sync)>>()
-            .as_slice()
-        ).await?;
+        let query = format!("DELETE FROM {} WHERE id = ANY($1)", T::TABLE_NAME);
+        connection.execute_query(&*query, &[&ids]).await?;
 
         Ok(())
     }
@@ -68,9 +58,3 @@ impl<C: CreateEntity<T>, T: PrimaryKeyEntity<P>, P: ColumnType> EntityCreateVec<
         Ok(())
     }
 }
-
-pub(crate) fn slice_to_sql_iter<'a, T: ToSql + Sync>(
-    s: &'a [T],
-) -> impl ExactSizeIterator<Item = &'a (dyn ToSql + Sync)> + 'a {
-    s.iter().map(|s| s as _)
-}
\ No newline at end of file
@@ -1,21 +1,121 @@
 //! Contains an [error enum](Error) for all types of errors in the ORM.
 //!
 //! Also contains a [result](Result) with that error type.
+//!
+//! Errors returned by Postgres are classified by their SQLSTATE code,
+//! so common errors like a unique violation can be handled without inspecting the [DbError](tokio_postgres::error::DbError):
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # conn.execute_batch("CREATE TEMPORARY TABLE error_example(id int4 PRIMARY KEY)").await.unwrap();
+//! conn.execute_query("INSERT INTO error_example VALUES (1)", &[]).await.unwrap();
+//!
+//! match conn.execute_query("INSERT INTO error_example VALUES (1)", &[]).await {
+//!     Err(Error::UniqueViolation { constraint, statement, .. }) => {
+//!         assert_eq!(constraint.as_deref(), Some("error_example_pkey"));
+//!         assert_eq!(statement, "INSERT INTO error_example VALUES (1)");
+//!     }
+//!     _ => panic!("Expected unique violation"),
+//! }
+//! # });
+//! ```
 
 use std::fmt::{Debug, Display, Formatter};
 
+use tokio_postgres::error::SqlState;
+
 /// Type alias for Result with the custom [Error]
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Error enum holding all possible errors that can be thrown by the ORM.
 ///
 /// This does implement [Error](std::error::Error) and contains tokio-postgres [Error](tokio_postgres::Error) if any occur.
+///
+/// Errors of Postgres are classified into the typed variants by [Error::from_postgres].
+/// All other errors of Postgres are returned as [Error::Postgres].
 #[derive(Debug)]
 pub enum Error {
-    /// Variant for [tokio_postgres::Error]
+    /// Variant for [tokio_postgres::Error] not matching any of the typed variants
     Postgres(tokio_postgres::Error),
     /// Variant for custom error message
     String(String),
+    /// The row targeted by the statement does not exist, e.g. when updating or removing an entity
+    NotFound {
+        /// The failing statement
+        statement: String,
+    },
+    /// A unique constraint was violated (SQLSTATE 23505)
+    UniqueViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// A foreign key constraint was violated (SQLSTATE 23503)
+    ForeignKeyViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// A check constraint was violated (SQLSTATE 23514)
+    CheckViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// An exclusion constraint was violated (SQLSTATE 23P01)
+    ExclusionViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// The transaction could not be serialized and should be retried (SQLSTATE 40001)
+    SerializationFailure {
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// A deadlock was detected and the transaction should be retried (SQLSTATE 40P01)
+    Deadlock {
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// The connection to the database was closed or lost (SQLSTATE class 08 or server shutdown)
+    ConnectionLost {
+        /// The failing statement
+        statement: String,
+        /// The original error
+        source: tokio_postgres::Error,
+    },
+    /// A column of a row could not be mapped to the expected type
+    RowMapping {
+        /// Name of the column
+        column: String,
+        /// Name of the expected Rust type
+        expected: String,
+        /// Name of the actual Postgres type, `NULL` for null values
+        actual: String,
+        /// The statement that returned the row, empty if unknown
+        statement: String,
+    },
 }
 
 impl Error {
@@ -24,6 +124,82 @@ impl Error {
     pub fn from_str(error: &str) -> Self {
         Self::String(String::from(error))
     }
+
+    /// Classifies an error of Postgres by its SQLSTATE code.
+    ///
+    /// This should be used by all implementations of [DatabaseConnection](crate::connection::DatabaseConnection).
+    pub fn from_postgres(error: tokio_postgres::Error, statement: &str) -> Self {
+        let statement = statement.to_string();
+
+        if error.is_closed() {
+            return Self::ConnectionLost { statement, source: error };
+        }
+
+        let Some(code) = error.code().cloned() else {
+            return Self::Postgres(error);
+        };
+
+        let constraint = error.as_db_error().and_then(|e| e.constraint()).map(|v| v.to_string());
+
+        match code {
+            SqlState::UNIQUE_VIOLATION => Self::UniqueViolation { constraint, statement, source: error },
+            SqlState::FOREIGN_KEY_VIOLATION => Self::ForeignKeyViolation { constraint, statement, source: error },
+            SqlState::CHECK_VIOLATION => Self::CheckViolation { constraint, statement, source: error },
+            SqlState::EXCLUSION_VIOLATION => Self::ExclusionViolation { constraint, statement, source: error },
+            SqlState::T_R_SERIALIZATION_FAILURE => Self::SerializationFailure { statement, source: error },
+            SqlState::T_R_DEADLOCK_DETECTED => Self::Deadlock { statement, source: error },
+            SqlState::ADMIN_SHUTDOWN | SqlState::CRASH_SHUTDOWN | SqlState::CANNOT_CONNECT_NOW => {
+                Self::ConnectionLost { statement, source: error }
+            }
+            code if code.code().starts_with("08") => Self::ConnectionLost { statement, source: error },
+            _ => Self::Postgres(error),
+        }
+    }
+
+    /// Sets the statement of a [RowMapping](Error::RowMapping) error, other errors are returned unchanged.
+    pub fn with_statement(self, statement: &str) -> Self {
+        match self {
+            Error::RowMapping { column, expected, actual, .. } => Error::RowMapping {
+                column,
+                expected,
+                actual,
+                statement: statement.to_string(),
+            },
+            error => error,
+        }
+    }
+
+    /// Returns the failing statement, if this error was caused by one.
+    pub fn statement(&self) -> Option<&str> {
+        match self {
+            Error::Postgres(_) | Error::String(_) => None,
+            Error::RowMapping { statement, .. } if statement.is_empty() => None,
+            Error::NotFound { statement }
+            | Error::UniqueViolation { statement, .. }
+            | Error::ForeignKeyViolation { statement, .. }
+            | Error::CheckViolation { statement, .. }
+            | Error::ExclusionViolation { statement, .. }
+            | Error::SerializationFailure { statement, .. }
+            | Error::Deadlock { statement, .. }
+            | Error::ConnectionLost { statement, .. }
+            | Error::RowMapping { statement, .. } => Some(statement),
+        }
+    }
+
+    /// Returns the SQLSTATE code of the error returned by Postgres, if any.
+    pub fn code(&self) -> Option<&SqlState> {
+        match self {
+            Error::Postgres(source)
+            | Error::UniqueViolation { source, .. }
+            | Error::ForeignKeyViolation { source, .. }
+            | Error::CheckViolation { source, .. }
+            | Error::ExclusionViolation { source, .. }
+            | Error::SerializationFailure { source, .. }
+            | Error::Deadlock { source, .. }
+            | Error::ConnectionLost { source, .. } => source.code(),
+            Error::String(_) | Error::NotFound { .. } | Error::RowMapping { .. } => None,
+        }
+    }
 }
 
 impl Display for Error {
@@ -31,11 +207,42 @@ impl Display for Error {
         match self {
             Error::Postgres(error) => std::fmt::Display::fmt(&error, f),
             Error::String(error) => std::fmt::Display::fmt(&error, f),
+            Error::NotFound { statement } => write!(f, "no row found (statement: {})", statement),
+            Error::UniqueViolation { source, statement, .. }
+            | Error::ForeignKeyViolation { source, statement, .. }
+            | Error::CheckViolation { source, statement, .. }
+            | Error::ExclusionViolation { source, statement, .. }
+            | Error::SerializationFailure { source, statement }
+            | Error::Deadlock { source, statement }
+            | Error::ConnectionLost { source, statement } => write!(f, "{} (statement: {})", source, statement),
+            Error::RowMapping { column, expected, actual, statement } => {
+                write!(f, "failed to map column {} of type {} to {}", column, actual, expected)?;
+
+                if !statement.is_empty() {
+                    write!(f, " (statement: {})", statement)?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Postgres(source)
+            | Error::UniqueViolation { source, .. }
+            | Error::ForeignKeyViolation { source, .. }
+            | Error::CheckViolation { source, .. }
+            | Error::ExclusionViolation { source, .. }
+            | Error::SerializationFailure { source, .. }
+            | Error::Deadlock { source, .. }
+            | Error::ConnectionLost { source, .. } => Some(source),
+            Error::String(_) | Error::NotFound { .. } | Error::RowMapping { .. } => None,
+        }
+    }
+}
 
 impl From<tokio_postgres::Error> for Error {
     fn from(value: tokio_postgres::Error) -> Self {
@@ -0,0 +1,271 @@
+//! # Full-text search
+//! [TsVector] maps to the `tsvector` type of Postgres. A [TsQuery] is built from a search text with one of the
+//! tsquery functions of Postgres:
+//!
+//! | Constructor          | Postgres function      | Example input               |
+//! |----------------------|------------------------|-----------------------------|
+//! | [TsQuery::plain]     | `plainto_tsquery`      | `red shoes`                 |
+//! | [TsQuery::websearch] | `websearch_to_tsquery` | `"red shoes" or boots -kids` |
+//! | [TsQuery::phrase]    | `phraseto_tsquery`     | `red shoes`                 |
+//! | [TsQuery::raw]       | `to_tsquery`           | `red & (shoe \| boot)`      |
+//!
+//! The query is used for the [matches](crate::prelude::FullTextQueryColumn::matches) condition,
+//! for [ranking](crate::prelude::TsVectorVirtualColumn) and for [headlines](crate::prelude::StringVirtualColumn::ts_headline).
+//! String columns can be searched directly, they are converted with `to_tsvector` using the configuration of the query.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleProduct {
+//!     id: u32,
+//!     description: String,
+//!     #[index(using = "gin")]
+//!     #[generated = "to_tsvector('english', description)"]
+//!     search: TsVector,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # ExampleProduct::create_table_if_not_exists(&conn).await.unwrap();
+//! ExampleProductCreate {
+//!     description: String::from("Red running shoes"),
+//! }.insert(&conn).await.unwrap();
+//!
+//! let query = TsQuery::websearch("english", "running shoe");
+//! let results = ExampleProduct::query()
+//!     .condition(ExampleProductColumn::SEARCH.matches(&query))
+//!     .order(&ExampleProductColumn::SEARCH.ts_rank(&query), OrderDirection::DESC)
+//!     .fetch(&conn).await.unwrap();
+//! # assert_eq!(results.len(), 1);
+//! # ExampleProduct::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
+//!
+//! ## Schema
+//! A field with `#[generated = "..."]` is created as stored generated column (`GENERATED ALWAYS AS (...) STORED`).
+//! Generated fields are neither inserted nor updated and are not part of the create struct.
+//! Their value is only available after fetching the entity again.
+//!
+//! `#[index(...)]` creates an index after the table. On a field, it indexes the column of the field.
+//! On the struct, it indexes `columns(a, b)` or an expression, e.g. `expression = "to_tsvector('english', description)"`.
+//! - `using` selects the index method, e.g. `gin` or `gist`. It defaults to `btree`.
+//! - `name = "..."` names the index.
+
+use std::error::Error;
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::{BoxedSql, IntoSql, SqlType};
+
+const WEIGHT_SHIFT: u16 = 14;
+const POSITION_MASK: u16 = 0x3FFF;
+
+/// Postgres tsvector, a sorted list of distinct lexemes with their positions, see [here](crate::full_text).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TsVector {
+    lexemes: Vec<TsLexeme>,
+}
+
+impl TsVector {
+    /// Creates a tsvector from lexemes.
+    ///
+    /// Postgres sorts the lexemes and merges duplicates when storing them.
+    pub fn new(lexemes: Vec<TsLexeme>) -> Self {
+        Self { lexemes }
+    }
+
+    /// Returns all lexemes
+    pub fn lexemes(&self) -> &[TsLexeme] {
+        &self.lexemes
+    }
+
+    /// Returns the lexeme for the word, if present
+    pub fn get(&self, word: &str) -> Option<&TsLexeme> {
+        self.lexemes.iter().find(|lexeme| lexeme.word == word)
+    }
+}
+
+/// Lexeme of a [TsVector]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TsLexeme {
+    /// The normalized word
+    pub word: String,
+    /// Positions of the word in the document, may be empty
+    pub positions: Vec<TsPosition>,
+}
+
+/// Position of a [TsLexeme]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TsPosition {
+    /// Position of the word, between 1 and 16383
+    pub position: u16,
+    /// Weight of the position
+    pub weight: TsWeight,
+}
+
+/// Weight of a [TsPosition], A being the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TsWeight {
+    #[allow(missing_docs)]
+    A,
+    #[allow(missing_docs)]
+    B,
+    #[allow(missing_docs)]
+    C,
+    #[allow(missing_docs)]
+    D,
+}
+
+impl TsPosition {
+    fn to_raw(self) -> u16 {
+        let weight = match self.weight {
+            TsWeight::A => 3,
+            TsWeight::B => 2,
+            TsWeight::C => 1,
+            TsWeight::D => 0,
+        };
+
+        (weight << WEIGHT_SHIFT) | (self.position & POSITION_MASK)
+    }
+
+    fn from_raw(raw: u16) -> Self {
+        let weight = match raw >> WEIGHT_SHIFT {
+            3 => TsWeight::A,
+            2 => TsWeight::B,
+            1 => TsWeight::C,
+            _ => TsWeight::D,
+        };
+
+        Self { position: raw & POSITION_MASK, weight }
+    }
+}
+
+impl SqlType for TsVector {
+    const SQL_TYPE: &'static str = "tsvector";
+}
+
+impl ToSql for TsVector {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&i32::try_from(self.lexemes.len())?.to_be_bytes());
+
+        for lexeme in &self.lexemes {
+            if lexeme.word.is_empty() || lexeme.word.contains('\0') {
+                return Err("invalid lexeme".into());
+            }
+            out.extend_from_slice(lexeme.word.as_bytes());
+            out.extend_from_slice(&[0]);
+
+            // Postgres requires strictly ascending positions
+            let mut positions = lexeme.positions.clone();
+            positions.sort_by_key(|position| position.position);
+            positions.dedup_by_key(|position| position.position);
+
+            out.extend_from_slice(&u16::try_from(positions.len())?.to_be_bytes());
+            for position in positions {
+                out.extend_from_slice(&position.to_raw().to_be_bytes());
+            }
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TS_VECTOR
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for TsVector {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let (count, mut raw) = raw.split_first_chunk::<4>().ok_or("invalid tsvector")?;
+        let count = i32::from_be_bytes(*count);
+
+        let mut lexemes = Vec::with_capacity(count.max(0) as usize);
+        for _ in 0..count {
+            let end = raw.iter().position(|byte| *byte == 0).ok_or("invalid tsvector")?;
+            let word = std::str::from_utf8(&raw[..end])?.to_string();
+
+            let (position_count, rest) = raw[end + 1..].split_first_chunk::<2>().ok_or("invalid tsvector")?;
+            let position_count = u16::from_be_bytes(*position_count) as usize;
+            if rest.len() < position_count * 2 {
+                return Err("invalid tsvector".into());
+            }
+
+            let positions = rest[..position_count * 2].chunks_exact(2)
+                .map(|chunk| TsPosition::from_raw(u16::from_be_bytes([chunk[0], chunk[1]])))
+                .collect();
+            raw = &rest[position_count * 2..];
+
+            lexemes.push(TsLexeme { word, positions });
+        }
+
+        Ok(Self { lexemes })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TS_VECTOR
+    }
+}
+
+/// Full-text search query, see [here](crate::full_text).
+///
+/// The configuration (e.g. `english` or `simple`) and the search text are bound as parameters.
+#[derive(Debug, Clone)]
+pub struct TsQuery {
+    config: BoxedSql,
+    sql: BoxedSql,
+}
+
+impl TsQuery {
+    /// Creates a query with `plainto_tsquery`, all words of the text must match
+    pub fn plain(config: impl IntoSql<String>, text: impl IntoSql<String>) -> Self {
+        Self::new("plainto_tsquery", config, text)
+    }
+
+    /// Creates a query with `websearch_to_tsquery`, supporting quoted phrases, `or` and `-` like search engines
+    pub fn websearch(config: impl IntoSql<String>, text: impl IntoSql<String>) -> Self {
+        Self::new("websearch_to_tsquery", config, text)
+    }
+
+    /// Creates a query with `phraseto_tsquery`, the words must match in the order of the text
+    pub fn phrase(config: impl IntoSql<String>, text: impl IntoSql<String>) -> Self {
+        Self::new("phraseto_tsquery", config, text)
+    }
+
+    /// Creates a query with `to_tsquery`, the text must be in the tsquery syntax, e.g. `red & (shoe | boot)`
+    pub fn raw(config: impl IntoSql<String>, text: impl IntoSql<String>) -> Self {
+        Self::new("to_tsquery", config, text)
+    }
+
+    fn new(function: &str, config: impl IntoSql<String>, text: impl IntoSql<String>) -> Self {
+        let config = config.into_boxed_sql();
+        let config = BoxedSql::new(format!("CAST({}::text AS regconfig)", config.sql), config.values);
+        let text = text.into_boxed_sql();
+
+        let mut values = config.values.clone();
+        values.extend(text.values);
+
+        Self {
+            sql: BoxedSql::new(format!("{}({},{})", function, config.sql, text.sql), values),
+            config,
+        }
+    }
+
+    /// Sql of the configuration as `regconfig`
+    pub(crate) fn get_config_sql(&self) -> BoxedSql {
+        self.config.clone()
+    }
+
+    /// Sql of the query as `tsquery`
+    pub(crate) fn get_sql(&self) -> BoxedSql {
+        self.sql.clone()
+    }
+}
@@ -1,4 +1,31 @@
 //! This module contains a simple json wrapper type for use in the ORM.
+//!
+//! Json columns ([Value] and [TypedJson]) are stored as JSONB.
+//! Besides equality, they support the [JSONB operators](crate::prelude::JsonQueryColumn)
+//! and [path extraction](crate::prelude::JsonVirtualColumn):
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! use serde_json::{json, Value};
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleJsonEntity {
+//!     id: u32,
+//!     data: Value,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # ExampleJsonEntity::create_table_if_not_exists(&conn).await.unwrap();
+//! let results = ExampleJsonEntity::query()
+//!     .condition(ExampleJsonEntityColumn::DATA.contains(json!({"active": true}))
+//!         .and(ExampleJsonEntityColumn::DATA.json_get_text("name").like("A%")))
+//!     .order(&ExampleJsonEntityColumn::DATA.json_get_path_text(vec![String::from("address"), String::from("city")]), OrderDirection::ASC)
+//!     .fetch(&conn).await.unwrap();
+//! # ExampleJsonEntity::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
 
 use std::error::Error;
 use std::fmt::Debug;
@@ -9,6 +36,8 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
+use crate::prelude::ColumnType;
+
 /// This is a basic wrapper type to provide typed json effortlessly.
 #[derive(Debug, Clone)]
 pub struct TypedJson<T: Serialize + DeserializeOwned + Debug + Clone + Send + Sync + 'static>(pub T);
@@ -78,3 +107,11 @@ impl<T: Serialize + DeserializeOwned + Debug + Clone + Send + Sync + 'static> Fr
         Self(value)
     }
 }
+
+/// Marker trait for all types stored as JSONB, enabling [JsonQueryColumn](crate::prelude::JsonQueryColumn)
+/// and [JsonVirtualColumn](crate::prelude::JsonVirtualColumn) for their columns.
+pub trait JsonColumnType: ColumnType {}
+
+impl JsonColumnType for Value {}
+
+impl<T: Serialize + DeserializeOwned + Debug + Clone + Send + Sync + 'static> JsonColumnType for TypedJson<T> {}
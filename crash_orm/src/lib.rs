@@ -9,7 +9,14 @@
 //!- [Entity](entity)
 //!  - [Property Types](column_value)
 //!  - [Relations](relations)
+//!  - [Custom Types](sql_type)
+//!  - [Enums](db_enum)
+//!  - [Ranges](range)
+//!  - [Additional Types](types)
+//!  - [Full-text search](full_text)
 //!- [Query](query)
+//!  - [Common table expressions](cte)
+//!  - [Custom functions and operators](mod@sql_function)
 //!- [Schema](schema)
 //!- [Migration](migration)
 //!
@@ -91,6 +98,13 @@ pub mod result_mapping;
 #[cfg(feature = "json")]
 pub mod json;
 pub mod raw_query_builder;
+pub mod db_enum;
+pub mod sql_type;
+pub mod range;
+pub mod types;
+pub mod full_text;
+pub mod cte;
+pub mod sql_function;
 
 pub mod prelude {
     //! Reexports all required modules and crates
@@ -118,6 +132,15 @@ pub mod prelude {
     #[cfg(feature = "json")]
     pub use crate::json::*;
     pub use crate::raw_query_builder::*;
+    pub use crate::db_enum::*;
+    pub use crate::sql_type::*;
+    pub use crate::range::*;
+    #[allow(unused_imports)]
+    pub use crate::types::*;
+    pub use crate::full_text::*;
+    pub use crate::cte::*;
+    pub use crate::sql_function::*;
+    pub use crate::{sql_function, sql_operator};
 
     pub extern crate tokio_postgres as postgres;
 }
\ No newline at end of file
@@ -54,7 +54,84 @@
 //! ```
 //! 
 //! IMPORTANT: The returned string from get_name MUST be UNIQUE across ALL migrations.
+//!
+//! ## Connection Types
+//! Migrations and migration managers are generic over the [DatabaseConnection](crate::prelude::DatabaseConnection)
+//! they are executed with. The default is [CrashOrmDatabaseConnection](crate::prelude::CrashOrmDatabaseConnection),
+//! which is used by the examples above.
+//!
+//! Implement them for all connections to execute your migrations with any connection,
+//! e.g. a tokio-postgres [Client](crate::postgres::Client) or inside a [Transaction](crate::postgres::Transaction):
+//!
+//! ```
+//! use crash_orm::async_trait::async_trait;
+//! use crash_orm::prelude::*;
+//!
+//! pub struct MigrationManager;
+//!
+//! impl<C: DatabaseConnection> CrashOrmMigrationManager<C> for MigrationManager {
+//!     fn get_migrations() -> Vec<Box<dyn Migration<C>>> {
+//!         vec![
+//!             Box::new(ExampleMigration),
+//!         ]
+//!     }
+//! }
+//!
+//! pub struct ExampleMigration;
+//!
+//! #[async_trait]
+//! impl<C: DatabaseConnection> Migration<C> for ExampleMigration {
+//!     async fn up(&self, conn: &C) -> crash_orm::Result<()> {
+//!         Ok(())
+//!     }
+//!
+//!     async fn down(&self, conn: &C) -> crash_orm::Result<()> {
+//!         Ok(())
+//!     }
+//!
+//!     fn get_name(&self) -> &str {
+//!         "ExampleMigration"
+//!     }
+//! }
+//! ```
+//!
+//! A migration manager implemented for all connections can only contain migrations implemented for all connections.
+//! [SqlMigration]s support all connections.
 //! 
+//! ## SQL Migrations
+//! Migrations can also be written as raw SQL files.
+//! Place them in a directory with the naming scheme `NNNN_name.up.sql` and `NNNN_name.down.sql`:
+//!
+//! ```text
+//! migrations/0001_create_users.up.sql
+//! migrations/0001_create_users.down.sql
+//! migrations/0002_add_email.up.sql
+//! ```
+//!
+//! The name of such a migration is the file name without suffix, e.g. `0001_create_users`.
+//! The down file is optional, but migrating down without it will fail.
+//!
+//! You can embed them at compile time with [include_sql_migrations!](crate::derive::include_sql_migrations)
+//! (the path is relative to your crate root) or load them at runtime with [SqlMigration::load_dir].
+//! Both return a vector of migrations, so they can be mixed with your Rust migrations:
+//!
+//! ```ignore
+//! use crash_orm::prelude::*;
+//!
+//! pub struct MigrationManager;
+//!
+//! impl CrashOrmMigrationManager for MigrationManager {
+//!     fn get_migrations() -> Vec<Box<dyn Migration>> {
+//!         let mut migrations = include_sql_migrations!("migrations");
+//!         migrations.push(Box::new(ExampleMigration));
+//!         migrations
+//!     }
+//! }
+//! ```
+//!
+//! Cargo does not notice new or removed files in the directory of [include_sql_migrations!](crate::derive::include_sql_migrations).
+//! Add a `build.rs` with `println!("cargo:rerun-if-changed=migrations");` to rebuild on such changes.
+//!
 //! ## Execute Migrations
 //! On Startup of your app, you should call the migrate_up method of your migration manager:
 //! 
@@ -80,12 +157,39 @@
 //! You should take care of the potential Err returned by this function since this likely means that parts of your migration failed.
 //! 
 //! migrate_up terminates after the first error, no following statements are executed.
+//!
+//! ## Dry Run
+//! To review the SQL of a migration run before executing it, use [dry_run](CrashOrmMigrationManager::dry_run).
+//! It returns the statements of all affected migrations as SQL script without modifying the database.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! # pub struct MigrationManager;
+//! #
+//! # impl CrashOrmMigrationManager for MigrationManager {
+//! #     fn get_migrations() -> Vec<Box<dyn Migration>> {
+//! #         vec![]
+//! #     }
+//! # }
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! let script = MigrationManager::dry_run(&conn, MigrationAction::Up).await.unwrap();
+//! println!("{}", script);
+//! # });
+//! ```
+//!
+//! Statements executed directly on the tokio-postgres client instead of the [DatabaseConnection](crate::prelude::DatabaseConnection)
+//! methods cannot be captured and **will** be executed.
 
 pub use entity::*;
 pub use migration::*;
 pub use migration_manager::*;
+pub use sql_migration::*;
 
 mod entity;
 mod migration;
 mod migration_manager;
+mod sql_migration;
 
@@ -1,16 +1,20 @@
 use async_trait::async_trait;
 
-use crate::prelude::CrashOrmDatabaseConnection;
+use crate::prelude::{CrashOrmDatabaseConnection, DatabaseConnection};
 
 /// Trait implemented for migrations
+///
+/// The generic parameter is the connection the migration is executed with.
+/// It defaults to [CrashOrmDatabaseConnection], implement it for all [DatabaseConnection]s to run the migration
+/// with any connection, e.g. inside a [Transaction](crate::postgres::Transaction).
 #[async_trait]
-pub trait Migration: Send + Sync {
+pub trait Migration<C: DatabaseConnection = CrashOrmDatabaseConnection>: Send + Sync {
     /// Migrate the database up
-    async fn up(&self, conn: &CrashOrmDatabaseConnection) -> crate::Result<()>;
+    async fn up(&self, conn: &C) -> crate::Result<()>;
 
     /// Migrate the database down
-    async fn down(&self, conn: &CrashOrmDatabaseConnection) -> crate::Result<()>;
+    async fn down(&self, conn: &C) -> crate::Result<()>;
 
     /// Name of the migration.
     fn get_name(&self) -> &str;
-}
\ No newline at end of file
+}
@@ -3,91 +3,192 @@ use chrono::Utc;
 
 use crate::migration::entity::{CrashOrmMigrationRecord, CrashOrmMigrationRecordColumn};
 use crate::migration::migration::Migration;
-use crate::prelude::{CrashOrmDatabaseConnection, CrashOrmMigrationRecordCreate, CreateEntity, Entity, EqualQueryColumn, OrderDirection, Schema};
+use crate::prelude::{CrashOrmDatabaseConnection, CrashOrmMigrationRecordCreate, CreateEntity, DatabaseConnection, Entity, EntitySchema, EqualQueryColumn, OrderDirection, RecordingDatabaseConnection, Schema};
+
+/// Action of a [CrashOrmMigrationManager], used to preview the SQL with [dry_run](CrashOrmMigrationManager::dry_run).
+#[derive(Clone, Debug)]
+pub enum MigrationAction {
+    /// See [migrate_up](CrashOrmMigrationManager::migrate_up)
+    Up,
+    /// See [migrate_down_to](CrashOrmMigrationManager::migrate_down_to)
+    DownTo(String),
+    /// See [migrate_down_prev](CrashOrmMigrationManager::migrate_down_prev)
+    DownPrev,
+}
 
 /// Trait to be implemented for a migration manager as documented [here](crate::migration).
+///
+/// The generic parameter is the connection the migrations are executed with, see [Migration].
 #[async_trait]
-pub trait CrashOrmMigrationManager: Sync + Send + 'static {
+pub trait CrashOrmMigrationManager<C: DatabaseConnection = CrashOrmDatabaseConnection>: Sync + Send + 'static {
     /// Specifies the migrations for this manager.
-    fn get_migrations() -> Vec<Box<dyn Migration>>;
+    fn get_migrations() -> Vec<Box<dyn Migration<C>>>;
+
+    /// Specifies the entities compared with the database by [schema_diff](Self::schema_diff).
+    ///
+    /// The default implementation returns no entities.
+    fn get_entities() -> Vec<EntitySchema<C>> {
+        vec![]
+    }
 
     /// Function used to migrate your database to the latest migration.
-    async fn migrate_up(conn: &CrashOrmDatabaseConnection) -> crate::Result<()> {
+    async fn migrate_up(conn: &C) -> crate::Result<()> {
         CrashOrmMigrationRecord::create_table_if_not_exists(conn).await?;
 
-        let local_migrations = Self::get_migrations();
+        for local_migration in Self::pending_migrations(conn).await? {
+            local_migration.up(conn).await?;
+
+            let migration_entry = CrashOrmMigrationRecordCreate {
+                name: local_migration.get_name().to_string(),
+                executed_at: Utc::now(),
+            };
+
+            migration_entry.insert(conn).await?;
+        }
+
+        Ok(())
+    }
+
+    /// This function migrates your database down to the desired version
+    async fn migrate_down_to(conn: &C, name: &str) -> crate::Result<()> {
+        for migration in Self::migrations_down_to(conn, name).await? {
+            Self::revert_migration(conn, &*migration).await?;
+        }
 
-        for local_migration in local_migrations {
-            let name = local_migration.get_name();
+        Ok(())
+    }
 
-            let migration_in_db = CrashOrmMigrationRecord::query()
-                .condition(CrashOrmMigrationRecordColumn::NAME.equals(name))
-                .fetch(conn)
-                .await?;
+    /// Migrate down to the previous migration
+    async fn migrate_down_prev(conn: &C) -> crate::Result<()> {
+        let local_migration = Self::migration_down_prev(conn).await?;
+        Self::revert_migration(conn, &*local_migration).await
+    }
 
-            if migration_in_db.is_empty() {
-                local_migration.up(conn).await?;
+    /// Returns the SQL script the [MigrationAction] would execute, without modifying the database.
+    ///
+    /// The migrations are executed on a connection in [capture mode](RecordingDatabaseConnection::recording),
+    /// the bookkeeping statements for the migration records are not included.
+    async fn dry_run(conn: &C, action: MigrationAction) -> crate::Result<String>
+    where
+        C: RecordingDatabaseConnection,
+    {
+        let (migrations, up) = match action {
+            MigrationAction::Up => (Self::pending_migrations(conn).await?, true),
+            MigrationAction::DownTo(name) => (Self::migrations_down_to(conn, &name).await?, false),
+            MigrationAction::DownPrev => (vec![Self::migration_down_prev(conn).await?], false),
+        };
+
+        let recording = conn.recording();
+        let mut script = String::new();
 
-                let migration_entry = CrashOrmMigrationRecordCreate {
-                    name: name.to_string(),
-                    executed_at: Utc::now(),
-                };
+        for migration in migrations {
+            if up {
+                script.push_str(&format!("-- up: {}\n", migration.get_name()));
+                migration.up(&recording).await?;
+            } else {
+                script.push_str(&format!("-- down: {}\n", migration.get_name()));
+                migration.down(&recording).await?;
+            }
 
-                migration_entry.insert(conn).await?;
+            for statement in recording.take_recorded_statements() {
+                script.push_str(&format!("{}\n", statement));
             }
         }
 
-        Ok(())
+        Ok(script)
     }
 
-    /// This function migrates your database down to the desired version
-    async fn migrate_down_to(conn: &CrashOrmDatabaseConnection, name: &str) -> crate::Result<()> {
+    /// Returns the SQL script changing the tables of all [entities](Self::get_entities) to match their structs,
+    /// see [Schema::diff_table].
+    ///
+    /// The database is not modified. Use it to find changes of your entities without a migration.
+    async fn schema_diff(conn: &C) -> crate::Result<String>
+    where
+        C: RecordingDatabaseConnection,
+    {
+        let mut script = String::new();
+
+        for entity in Self::get_entities() {
+            for statement in entity.diff_table(conn).await? {
+                script.push_str(&format!("{};\n", statement.trim_end().trim_end_matches(';')));
+            }
+        }
+
+        Ok(script)
+    }
+
+    /// Returns all local migrations which have not been executed yet, in execution order.
+    async fn pending_migrations(conn: &C) -> crate::Result<Vec<Box<dyn Migration<C>>>> {
+        let local_migrations = Self::get_migrations();
+
+        if !CrashOrmMigrationRecord::table_exists(conn).await? {
+            return Ok(local_migrations);
+        }
+
+        let executed_migrations = CrashOrmMigrationRecord::get_all(conn).await?;
+
+        Ok(local_migrations.into_iter()
+            .filter(|m| !executed_migrations.iter().any(|record| record.name == m.get_name()))
+            .collect())
+    }
+
+    /// Returns the migrations reverted by [migrate_down_to](Self::migrate_down_to), in execution order.
+    #[doc(hidden)]
+    async fn migrations_down_to(conn: &C, name: &str) -> crate::Result<Vec<Box<dyn Migration<C>>>> {
         let mut local_migrations = Self::get_migrations();
         local_migrations.reverse();
 
-        let latest = CrashOrmMigrationRecord::query()
-            .order(&CrashOrmMigrationRecordColumn::ID, OrderDirection::DESC)
-            .fetch_single(conn).await?.unwrap();
+        let latest = latest_migration_record(conn).await?;
 
+        let mut migrations = vec![];
         let mut started = false;
         for migration in local_migrations {
             if !started && migration.get_name() == latest.name {
                 started = true;
             }
 
-            if started {
-                migration.down(conn).await?;
+            let reached = migration.get_name() == name;
 
-                CrashOrmMigrationRecord::delete()
-                    .condition(CrashOrmMigrationRecordColumn::NAME.equals(migration.get_name()))
-                    .execute(conn).await?;
+            if started {
+                migrations.push(migration);
             }
 
-            if migration.get_name() == name {
+            if reached {
                 break;
             }
         }
 
-        Ok(())
+        Ok(migrations)
     }
 
-    /// Migrate down to the previous migration
-    async fn migrate_down_prev(conn: &CrashOrmDatabaseConnection) -> crate::Result<()> {
-        let local_migrations = Self::get_migrations();
+    /// Returns the migration reverted by [migrate_down_prev](Self::migrate_down_prev).
+    #[doc(hidden)]
+    async fn migration_down_prev(conn: &C) -> crate::Result<Box<dyn Migration<C>>> {
+        let latest = latest_migration_record(conn).await?;
 
-        let latest = CrashOrmMigrationRecord::query()
-            .order(&CrashOrmMigrationRecordColumn::ID, OrderDirection::DESC)
-            .fetch_single(conn).await?
-            .unwrap();
-
-        let Some(local_migration) = local_migrations.iter().find(|m| m.get_name() == latest.name) else {
+        let Some(local_migration) = Self::get_migrations().into_iter().find(|m| m.get_name() == latest.name) else {
             return Err(crate::Error::from_str(&*format!("The previous migration {} was not found in local migrations", latest.name)));
         };
 
-        local_migration.down(conn).await?;
+        Ok(local_migration)
+    }
+
+    /// Migrates down a single migration and removes its record.
+    #[doc(hidden)]
+    async fn revert_migration(conn: &C, migration: &dyn Migration<C>) -> crate::Result<()> {
+        migration.down(conn).await?;
 
-        latest.remove(conn).await?;
+        CrashOrmMigrationRecord::delete()
+            .condition(CrashOrmMigrationRecordColumn::NAME.equals(migration.get_name()))
+            .execute(conn).await?;
 
         Ok(())
     }
 }
+
+async fn latest_migration_record(conn: &impl DatabaseConnection) -> crate::Result<CrashOrmMigrationRecord> {
+    CrashOrmMigrationRecord::query()
+        .order(&CrashOrmMigrationRecordColumn::ID, OrderDirection::DESC)
+        .fetch_single(conn).await?
+        .ok_or_else(|| crate::Error::from_str("No migration has been executed yet"))
+}
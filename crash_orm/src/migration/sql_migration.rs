@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::migration::migration::Migration;
+use crate::prelude::DatabaseConnection;
+
+/// Migration backed by raw SQL instead of Rust code.
+///
+/// SQL migrations are usually loaded from a directory of `NNNN_name.up.sql` / `NNNN_name.down.sql` files,
+/// either at runtime with [SqlMigration::load_dir] or at compile time with
+/// [include_sql_migrations!](crate::derive::include_sql_migrations).
+///
+/// The name of the migration is the file name without the `.up.sql` / `.down.sql` suffix, e.g. `0001_create_users`.
+#[derive(Clone, Debug)]
+pub struct SqlMigration {
+    name: String,
+    up: String,
+    down: Option<String>,
+}
+
+impl SqlMigration {
+    /// Creates a new SQL migration with the SQL executed when migrating up.
+    pub fn new(name: impl Into<String>, up: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            up: up.into(),
+            down: None,
+        }
+    }
+
+    /// Set the SQL executed when migrating down.
+    ///
+    /// Without down SQL, migrating down this migration will fail.
+    pub fn with_down(mut self, down: impl Into<String>) -> Self {
+        self.down = Some(down.into());
+        self
+    }
+
+    /// Loads all SQL migrations from a directory.
+    ///
+    /// Files must be named `NNNN_name.up.sql` and optionally `NNNN_name.down.sql`.
+    /// The migrations are ordered by their numeric prefix. Other files in the directory are ignored.
+    ///
+    /// SQL migrations can be executed with any [DatabaseConnection].
+    pub fn load_dir<C: DatabaseConnection>(dir: impl AsRef<Path>) -> crate::Result<Vec<Box<dyn Migration<C>>>> {
+        let dir = dir.as_ref();
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| crate::Error::String(format!("Failed to read migration directory {}: {}", dir.display(), e)))?;
+
+        let mut files = vec![];
+        for entry in entries {
+            let entry = entry.map_err(|e| crate::Error::String(format!("Failed to read migration directory {}: {}", dir.display(), e)))?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            if let Some((number, name, is_up)) = parse_sql_migration_file_name(&file_name)? {
+                files.push((number, name, is_up, entry.path()));
+            }
+        }
+
+        files.sort_by(|(number_a, name_a, _, _), (number_b, name_b, _, _)| {
+            number_a.cmp(number_b).then_with(|| name_a.cmp(name_b))
+        });
+
+        let mut migrations: Vec<SqlMigration> = vec![];
+        let mut down_files = vec![];
+        for (_, name, is_up, path) in files {
+            let sql = std::fs::read_to_string(&path)
+                .map_err(|e| crate::Error::String(format!("Failed to read migration file {}: {}", path.display(), e)))?;
+
+            if is_up {
+                if migrations.iter().any(|m| m.name == name) {
+                    return Err(crate::Error::String(format!("Duplicate SQL migration {}", name)));
+                }
+
+                migrations.push(SqlMigration::new(name, sql));
+            } else {
+                down_files.push((name, sql));
+            }
+        }
+
+        for (name, sql) in down_files {
+            let Some(migration) = migrations.iter_mut().find(|m| m.name == name) else {
+                return Err(crate::Error::String(format!("Found down migration {} without matching up migration", name)));
+            };
+
+            migration.down = Some(sql);
+        }
+
+        Ok(migrations.into_iter().map(|m| Box::new(m) as Box<dyn Migration<C>>).collect())
+    }
+}
+
+/// Parses `NNNN_name.up.sql` / `NNNN_name.down.sql` into the numeric prefix, the migration name and whether it is the up file.
+///
+/// Returns `None` for files that are not sql migrations.
+fn parse_sql_migration_file_name(file_name: &str) -> crate::Result<Option<(u64, String, bool)>> {
+    let (name, is_up) = if let Some(name) = file_name.strip_suffix(".up.sql") {
+        (name, true)
+    } else if let Some(name) = file_name.strip_suffix(".down.sql") {
+        (name, false)
+    } else {
+        return Ok(None);
+    };
+
+    let number = name.split_once('_')
+        .and_then(|(number, _)| number.parse::<u64>().ok())
+        .ok_or_else(|| crate::Error::String(format!("SQL migration {} must be named NNNN_name.up.sql or NNNN_name.down.sql", file_name)))?;
+
+    Ok(Some((number, name.to_string(), is_up)))
+}
+
+#[async_trait]
+impl<C: DatabaseConnection> Migration<C> for SqlMigration {
+    async fn up(&self, conn: &C) -> crate::Result<()> {
+        conn.execute_batch(&self.up).await?;
+        Ok(())
+    }
+
+    async fn down(&self, conn: &C) -> crate::Result<()> {
+        let Some(ref down) = self.down else {
+            return Err(crate::Error::String(format!("The SQL migration {} has no down migration", self.name)));
+        };
+
+        conn.execute_batch(down).await?;
+        Ok(())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -71,6 +71,121 @@
 //!     .add_order(&TestEntityColumn::TEST, OrderDirection::ASC);
 //! ```
 //!
+//! ### Grouping
+//! Select queries can be grouped by any column, including expressions.
+//! Groups are filtered with [having](Query::having), which translates to HAVING.
+//!
+//! ```rust
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct TestEntity {
+//! #    id: u32,
+//! #    name: String,
+//! # }
+//!
+//! // SELECT LOWER(name),COUNT(id) FROM public.test_entity GROUP BY LOWER(name) HAVING COUNT(id) > $1
+//! let name = TestEntityColumn::NAME.lowercase();
+//! let mut query = TestEntity::select_query::<(String, i64)>(&[&name, &TestEntityColumn::ID.count_column(false)])
+//!     .group_by(&name)
+//!     .having(TestEntityColumn::ID.count_column(false).greater_than(5));
+//! ```
+//!
+//! ### Subqueries
+//! Select queries can be nested in conditions with [in_query](crate::prelude::InQueryColumn::in_query),
+//! [exists](QueryCondition::exists) and [not_exists](QueryCondition::not_exists)
+//! or used as column with [VirtualColumn::subquery](crate::prelude::VirtualColumn::subquery).
+//! Columns of the outer query are referenced with [outer](crate::prelude::EntityColumn::outer).
+//!
+//! ```rust
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Customer {
+//! #    id: u32,
+//! # }
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Invoice {
+//! #    id: u32,
+//! #    customer: ManyToOne<Customer, u32>,
+//! #    paid: bool,
+//! # }
+//!
+//! // WHERE EXISTS(SELECT * FROM public.invoice WHERE (customer = customer.id) AND (paid = $1))
+//! let mut query = Customer::query()
+//!     .condition(QueryCondition::exists(Invoice::query().condition(
+//!         InvoiceColumn::CUSTOMER_PRIMARY.equals(CustomerColumn::ID.outer())
+//!             .and(InvoiceColumn::PAID.equals(false))
+//!     )));
+//! ```
+//!
+//! ### Expressions
+//! Virtual columns can calculate values, e.g. with [ArithmeticVirtualColumn](crate::prelude::ArithmeticVirtualColumn),
+//! [ConditionalVirtualColumn](crate::prelude::ConditionalVirtualColumn) or [Case](crate::prelude::Case).
+//! They can be selected, used in conditions, orders and as values of an update.
+//! With the features `with-chrono` or `with-time`, date and timestamp columns have `DateTimeVirtualColumn`,
+//! e.g. to group by week with `date_trunc`, and conditions like `within_last` in `DateTimeQueryColumn`.
+//! Other functions and operators, e.g. of extensions, are declared with [sql_function!](crate::sql_function!)
+//! and [sql_operator!](crate::sql_operator!).
+//!
+//! ```rust
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct TestEntity {
+//! #    id: u32,
+//! #    price: i32,
+//! #    discount: Option<i32>,
+//! # }
+//!
+//! // WHERE (price - COALESCE(discount,$1)) > $2
+//! let query = TestEntity::query()
+//!     .condition(TestEntityColumn::PRICE.sub(TestEntityColumn::DISCOUNT.coalesce(0)).greater_than(10));
+//!
+//! // UPDATE public.test_entity SET price = (price * $1) WHERE discount IS NULL
+//! let update = TestEntity::update_query()
+//!     .set(&TestEntityColumn::PRICE, TestEntityColumn::PRICE.mul(2))
+//!     .condition(TestEntityColumn::DISCOUNT.is_null());
+//! ```
+//!
+//! ### Limit
+//! [limit](Query::limit) and [offset](Query::offset) restrict the returned rows, e.g. for pagination.
+//!
+//! ### Combining queries
+//! Select queries with the same result can be combined with [union](Query::union), [union_all](Query::union_all),
+//! [intersect](Query::intersect) and [except](Query::except), even for different entities.
+//! The combined rows are selected as the table of the first entity,
+//! so order, limit and conditions added afterward apply to the combined result.
+//! Order and limit of a query before combining only apply to its own rows.
+//!
+//! ```rust
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Post {
+//! #    id: u32,
+//! #    title: String,
+//! #    created_at: chrono::DateTime<chrono::Utc>,
+//! # }
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Comment {
+//! #    id: u32,
+//! #    text: String,
+//! #    created_at: chrono::DateTime<chrono::Utc>,
+//! # }
+//!
+//! // SELECT * FROM ((SELECT title,created_at FROM public.post) UNION ALL (SELECT text,created_at FROM public.comment)) AS post
+//! //     ORDER BY created_at DESC LIMIT 20
+//! let feed = Post::select_query::<(String, chrono::DateTime<chrono::Utc>)>(&[&PostColumn::TITLE, &PostColumn::CREATED_AT])
+//!     .union_all(Comment::select_query(&[&CommentColumn::TEXT, &CommentColumn::CREATED_AT]))
+//!     .order(&PostColumn::CREATED_AT, OrderDirection::DESC)
+//!     .limit(20);
+//! ```
+//!
+//! ### Common table expressions
+//! Queries can be added as `WITH` expressions with [with_cte](Query::with_cte), including recursive ones for trees.
+//! See [here](crate::cte) for details.
+//!
 //! ### Execute Query
 //! When you are done building the query, you can finally execute it.
 //!
@@ -98,7 +213,7 @@ use std::sync::Arc;
 use tokio_postgres::types::ToSql;
 
 use crate::entity::slice_query_value_iter;
-use crate::prelude::{BoxedSql, ColumnType, DatabaseConnection, Entity, EntityColumn, QueryCondition, UntypedColumn};
+use crate::prelude::{BoxedSql, ColumnType, DatabaseConnection, Entity, EntityColumn, IntoSql, QueryCondition, RecursiveCte, UntypedColumn};
 use crate::result_mapping::ResultMapping;
 
 /// Marks a query as a SELECT query.
@@ -107,6 +222,9 @@ pub struct SelectQueryType;
 /// Marks a query as a DELETE query.
 pub struct DeleteQueryType;
 
+/// Marks a query as an UPDATE query.
+pub struct UpdateQueryType;
+
 /// Direction of the Order
 #[derive(Debug, Clone)]
 pub enum OrderDirection {
@@ -125,12 +243,66 @@ impl Display for OrderDirection {
     }
 }
 
+/// Source of the FROM clause of a select query, kept apart from the base query to alias it in subqueries.
+pub(crate) struct FromClause {
+    source: BoxedSql,
+    /// Name referencing the source in the query
+    name: String,
+    aliased: bool,
+}
+
+impl FromClause {
+    /// Selects from the table of `T`
+    pub(crate) fn table<T: Entity>() -> FromClause {
+        FromClause {
+            source: BoxedSql::new(format!("public.{}", T::TABLE_NAME), vec![]),
+            name: T::TABLE_NAME.to_string(),
+            aliased: false,
+        }
+    }
+
+    /// Selects from a common table expression
+    pub(crate) fn cte(name: &str) -> FromClause {
+        FromClause {
+            source: BoxedSql::new(name.to_string(), vec![]),
+            name: name.to_string(),
+            aliased: false,
+        }
+    }
+
+    /// Selects from a subquery, e.g. `(...) AS name`
+    pub(crate) fn subquery(source: BoxedSql, name: &str) -> FromClause {
+        FromClause {
+            source,
+            name: name.to_string(),
+            aliased: true,
+        }
+    }
+
+    fn into_boxed_sql(self) -> BoxedSql {
+        let mut boxed = self.source;
+        if self.aliased {
+            boxed.sql.push_str(&format!(" AS {}", self.name));
+        }
+        boxed
+    }
+}
+
 /// Struct representing a database query for an entity.
 pub struct Query<T: Entity, R: ResultMapping, QT> {
+    ctes: Vec<BoxedSql>,
+    recursive: bool,
     base_query: BoxedSql,
+    from: Option<FromClause>,
+    selected: Vec<BoxedSql>,
+    set: Vec<BoxedSql>,
     condition: Option<QueryCondition<T>>,
     group_by: Vec<BoxedSql>,
+    having: Option<QueryCondition<T>>,
     order: Vec<(BoxedSql, OrderDirection)>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    lenient: bool,
     phantom: PhantomData<(R, QT)>,
 }
 
@@ -138,57 +310,173 @@ impl<T: Entity, R: ResultMapping, QT> Query<T, R, QT> {
     /// Create a new query from a [BoxedSql]
     pub fn new(base_query: BoxedSql) -> Query<T, R, QT> {
         Self {
+            ctes: vec![],
+            recursive: false,
             base_query,
+            from: None,
+            selected: vec![],
+            set: vec![],
             condition: None,
             group_by: vec![],
+            having: None,
             order: vec![],
+            limit: None,
+            offset: None,
+            lenient: false,
             phantom: PhantomData,
         }
     }
 
+    /// Sets the source of the FROM clause appended to the base query.
+    pub(crate) fn from(mut self, from: FromClause) -> Query<T, R, QT> {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the selected columns of the base query, which are referenced by their position in GROUP BY and ORDER BY.
+    pub(crate) fn selecting(mut self, columns: &[&dyn UntypedColumn<T>]) -> Query<T, R, QT> {
+        self.selected = columns.iter().map(|v| v.get_sql()).collect();
+        self
+    }
+
+    /// Returns the position of a parameterized expression in the selected columns.
+    ///
+    /// The parameters of an expression are numbered again each time it is used,
+    /// so Postgres would not match e.g. `GROUP BY REPEAT(name,$2)` with the selected `REPEAT(name,$1)`.
+    fn positional(&self, column: BoxedSql) -> BoxedSql {
+        if column.values.is_empty() {
+            return column;
+        }
+
+        match self.selected.iter().position(|selected| selected.same_expression(&column)) {
+            Some(index) => BoxedSql::new((index + 1).to_string(), vec![]),
+            None => column,
+        }
+    }
+
     /// Set the condition for this query.
     pub fn condition(mut self, condition: QueryCondition<T>) -> Query<T, R, QT> {
         self.condition = Some(condition);
         self
     }
 
-    fn get_raw_query(self) -> (String, Vec<Arc<Box<dyn ToSql + Send + Sync>>>) {
-        let (mut query, mut values, mut index) = self.base_query.resolve(1);
-
-        if self.condition.is_some() {
-            let (condition_query, condition_values, next_index) =
-                self.condition.unwrap().resolve(index);
-            index = next_index;
-            values = condition_values;
-            query.push_str(" WHERE ");
-            query.push_str(&*condition_query);
+    /// Add a common table expression to this query, translated to `WITH name AS (subquery)`.
+    ///
+    /// The rows of the expression are selected with a [Cte](crate::cte::Cte), see [here](crate::cte).
+    pub fn with_cte<O: Entity, OR: ResultMapping>(mut self, name: &str, subquery: Query<O, OR, SelectQueryType>) -> Query<T, R, QT> {
+        let mut sql = subquery.into_boxed_sql();
+        sql.modify(|v| format!("{name} AS ({v})"));
+        self.ctes.push(sql);
+        self
+    }
+
+    /// Add a recursive common table expression to this query, translated to `WITH RECURSIVE`.
+    pub fn with_recursive_cte<O: Entity>(mut self, cte: RecursiveCte<O>) -> Query<T, R, QT> {
+        self.ctes.push(cte.into_boxed_sql());
+        self.recursive = true;
+        self
+    }
+
+    /// Converts this query into a [BoxedSql] to embed it as subquery in a query of `O`.
+    ///
+    /// If the subquery selects from a table or expression named like the table of `O`, it is aliased as `<table>_subquery`,
+    /// so [outer](crate::prelude::EntityColumn::outer) references the table of the outer query.
+    pub(crate) fn into_subquery_sql<O: Entity>(mut self) -> BoxedSql {
+        if let Some(from) = self.from.as_mut().filter(|from| from.name == O::TABLE_NAME) {
+            from.name = format!("{}_subquery", O::TABLE_NAME);
+            from.aliased = true;
         }
-        
+
+        self.into_boxed_sql()
+    }
+
+    /// Converts this query into a [BoxedSql] with unresolved placeholders, e.g. to use it as subquery.
+    pub(crate) fn into_boxed_sql(mut self) -> BoxedSql {
+        if let Some(from) = self.from.take() {
+            let from = from.into_boxed_sql();
+            self.base_query.sql.push_str(&format!(" FROM {}", from.sql));
+            self.base_query.values.extend(from.values);
+        }
+
+        let mut boxed = if self.ctes.is_empty() {
+            self.base_query
+        } else {
+            let mut ctes = vec![];
+            let mut values = vec![];
+
+            for cte in self.ctes {
+                ctes.push(cte.sql);
+                values.extend(cte.values);
+            }
+            values.extend(self.base_query.values);
+
+            let recursive = if self.recursive { "RECURSIVE " } else { "" };
+            BoxedSql::new(format!("WITH {}{} {}", recursive, ctes.join(","), self.base_query.sql), values)
+        };
+
+        if !self.set.is_empty() {
+            boxed.sql.push_str(" SET ");
+
+            let mut set = vec![];
+
+            for x in self.set {
+                set.push(x.sql);
+                boxed.values.extend(x.values);
+            }
+
+            boxed.sql.push_str(&set.join(","));
+        }
+
+        if let Some(condition) = self.condition {
+            boxed.sql.push_str(" WHERE ");
+            boxed.sql.push_str(&condition.boxed.sql);
+            boxed.values.extend(condition.boxed.values);
+        }
+
         if !self.group_by.is_empty() {
-            query.push_str(" GROUP BY ");
-            
+            boxed.sql.push_str(" GROUP BY ");
+
             let mut grouped_by = vec![];
 
             for x in self.group_by {
                 grouped_by.push(x.sql);
+                boxed.values.extend(x.values);
             }
-            
-            query.push_str(&*grouped_by.join(","));
+
+            boxed.sql.push_str(&*grouped_by.join(","));
+        }
+
+        if let Some(having) = self.having {
+            boxed.sql.push_str(" HAVING ");
+            boxed.sql.push_str(&having.boxed.sql);
+            boxed.values.extend(having.boxed.values);
         }
 
         if !self.order.is_empty() {
-            query.push_str(" ORDER BY ");
+            boxed.sql.push_str(" ORDER BY ");
             let mut orders = vec![];
 
             for (order_name, order_dir) in self.order {
-                let (order_query, order_values, _) = order_name.resolve(index);
-                values.extend(order_values);
-                orders.push(format!("{} {}", order_query, order_dir.to_string()));
+                orders.push(format!("{} {}", order_name.sql, order_dir.to_string()));
+                boxed.values.extend(order_name.values);
             }
 
-            query.push_str(&*orders.join(","));
+            boxed.sql.push_str(&*orders.join(","));
+        }
+
+        if let Some(limit) = self.limit {
+            boxed.sql.push_str(&format!(" LIMIT {limit}"));
+        }
+
+        if let Some(offset) = self.offset {
+            boxed.sql.push_str(&format!(" OFFSET {offset}"));
         }
 
+        boxed
+    }
+
+    fn get_raw_query(self) -> (String, Vec<Arc<Box<dyn ToSql + Send + Sync>>>) {
+        let (query, values, _) = self.into_boxed_sql().resolve(1);
         (query, values)
     }
 }
@@ -200,7 +488,7 @@ impl<T: Entity, R: ResultMapping> Query<T, R, SelectQueryType> {
         order: &dyn UntypedColumn<T>,
         order_direction: OrderDirection,
     ) -> Query<T, R, SelectQueryType> {
-        self.order.push((order.get_sql(), order_direction));
+        self.order.push((self.positional(order.get_sql()), order_direction));
         self
     }
 
@@ -212,32 +500,100 @@ impl<T: Entity, R: ResultMapping> Query<T, R, SelectQueryType> {
         order: &dyn UntypedColumn<T>,
         order_direction: OrderDirection,
     ) -> Query<T, R, SelectQueryType> {
-        self.order = vec![(order.get_sql(), order_direction)];
+        self.order = vec![(self.positional(order.get_sql()), order_direction)];
         self
     }
 
-    /// Add a grouping to this query
-    pub fn add_group_by<U: ColumnType>(
+    /// Add a grouping to this query.
+    ///
+    /// Any column can be used, e.g. an [EntityColumn] or an expression as [VirtualColumn](crate::prelude::VirtualColumn).
+    /// An expression with parameters, which is also selected, is grouped by its position, e.g. `GROUP BY 1`.
+    pub fn add_group_by(
         mut self,
-        group_by: &EntityColumn<U ,T>,
+        group_by: &dyn UntypedColumn<T>,
     ) -> Query<T, R, SelectQueryType> {
-        self.group_by.push(group_by.get_sql());
+        self.group_by.push(self.positional(group_by.get_sql()));
         self
     }
 
     /// Set the grouping for this query.
     ///
     /// This will OVERRIDE all previous grouping.
-    pub fn group_by<U: ColumnType>(
+    pub fn group_by(
         mut self,
-        group_by: &EntityColumn<U ,T>,
+        group_by: &dyn UntypedColumn<T>,
     ) -> Query<T, R, SelectQueryType> {
-        self.group_by = vec![group_by.get_sql()];
+        self.group_by = vec![self.positional(group_by.get_sql())];
+        self
+    }
+
+    /// Set the condition for the groups of this query, e.g. `HAVING COUNT(id) > 5`.
+    ///
+    /// The condition can use aggregates like [count_column](crate::prelude::CountColumn::count_column).
+    pub fn having(mut self, condition: QueryCondition<T>) -> Query<T, R, SelectQueryType> {
+        self.having = Some(condition);
+        self
+    }
+
+    /// Limit the number of returned rows, translated to LIMIT.
+    pub fn limit(mut self, limit: u64) -> Query<T, R, SelectQueryType> {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first rows, translated to OFFSET.
+    pub fn offset(mut self, offset: u64) -> Query<T, R, SelectQueryType> {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Combines the rows of both queries without duplicates, translated to UNION.
+    ///
+    /// See [here](crate::query#combining-queries) for details.
+    pub fn union<O: Entity>(self, other: Query<O, R, SelectQueryType>) -> Query<T, R, SelectQueryType> {
+        self.combine("UNION", other)
+    }
+
+    /// Combines the rows of both queries including duplicates, translated to UNION ALL.
+    pub fn union_all<O: Entity>(self, other: Query<O, R, SelectQueryType>) -> Query<T, R, SelectQueryType> {
+        self.combine("UNION ALL", other)
+    }
+
+    /// Returns the rows which are returned by both queries, translated to INTERSECT.
+    pub fn intersect<O: Entity>(self, other: Query<O, R, SelectQueryType>) -> Query<T, R, SelectQueryType> {
+        self.combine("INTERSECT", other)
+    }
+
+    /// Returns the rows of this query which are not returned by the other query, translated to EXCEPT.
+    pub fn except<O: Entity>(self, other: Query<O, R, SelectQueryType>) -> Query<T, R, SelectQueryType> {
+        self.combine("EXCEPT", other)
+    }
+
+    fn combine<O: Entity>(self, operator: &str, other: Query<O, R, SelectQueryType>) -> Query<T, R, SelectQueryType> {
+        let lenient = self.lenient;
+        let mut boxed = self.into_boxed_sql();
+        let other = other.into_boxed_sql();
+
+        boxed.modify(|v| format!("(({v}) {operator} ({}))", other.sql));
+        boxed.values.extend(other.values);
+
+        let mut query = Query::new(BoxedSql::new(String::from("SELECT *"), vec![]))
+            .from(FromClause::subquery(boxed, T::TABLE_NAME));
+        query.lenient = lenient;
+        query
+    }
+
+    /// Skip rows which can't be mapped into the result instead of returning [Error::RowMapping](crate::Error::RowMapping).
+    pub fn lenient(mut self) -> Query<T, R, SelectQueryType> {
+        self.lenient = true;
         self
     }
 
     /// Execute this query and returns the result as a vector of entities.
+    ///
+    /// Returns [Error::RowMapping](crate::Error::RowMapping) if a row can't be mapped, unless the query is [lenient](Self::lenient).
     pub async fn fetch(self, connection: &impl DatabaseConnection) -> crate::Result<Vec<R>> {
+        let lenient = self.lenient;
         let (query, values) = self.get_raw_query();
 
         let rows = connection
@@ -249,12 +605,24 @@ impl<T: Entity, R: ResultMapping> Query<T, R, SelectQueryType> {
             )
             .await?;
 
-        Ok(rows.into_iter().map(|r| R::from_row(r)).filter(|r| r.is_some()).map(|r| r.unwrap()).collect::<Vec<R>>())
+        let mut results = vec![];
+        for row in rows {
+            match R::from_row(row) {
+                Ok(result) => results.push(result),
+                Err(crate::Error::RowMapping { .. }) if lenient => {}
+                Err(e) => return Err(e.with_statement(&query)),
+            }
+        }
+
+        Ok(results)
     }
 
     /// Execute this query and returns a single result as an entity
+    ///
+    /// Returns [Error::RowMapping](crate::Error::RowMapping) if the row can't be mapped, unless the query is [lenient](Self::lenient).
     pub async fn fetch_single(self, connection: &impl DatabaseConnection) -> crate::Result<Option<R>> {
-        let (query, values) = self.get_raw_query();
+        let lenient = self.lenient;
+        let (query, values) = self.limit(1).get_raw_query();
 
         let row = connection
             .query_single(
@@ -265,10 +633,14 @@ impl<T: Entity, R: ResultMapping> Query<T, R, SelectQueryType> {
             )
             .await?;
         
-        if let Some(row) = row {
-            Ok(R::from_row(row))
-        } else {    
-            Ok(None)
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        match R::from_row(row) {
+            Ok(result) => Ok(Some(result)),
+            Err(crate::Error::RowMapping { .. }) if lenient => Ok(None),
+            Err(e) => Err(e.with_statement(&query)),
         }
     }
 }
@@ -290,3 +662,33 @@ impl<T: Entity, R: ResultMapping> Query<T, R, DeleteQueryType> {
         Ok(())
     }
 }
+
+impl<T: Entity, R: ResultMapping> Query<T, R, UpdateQueryType> {
+    /// Set the column to the value for all matching rows.
+    ///
+    /// The value can be a value or any column, e.g. `STOCK.sub(1)`.
+    pub fn set<X: ColumnType>(mut self, column: &EntityColumn<X, T>, value: impl IntoSql<X>) -> Query<T, R, UpdateQueryType> {
+        let mut sql = column.get_sql();
+        let value = value.into_boxed_sql();
+        sql.modify(|v| format!("{} = {}", v, value.sql));
+        sql.values.extend(value.values);
+        self.set.push(sql);
+        self
+    }
+
+    /// Execute this query without a result
+    pub async fn execute(self, connection: &impl DatabaseConnection) -> crate::Result<()> {
+        let (query, values) = self.get_raw_query();
+
+        connection
+            .execute_query(
+                &query,
+                slice_query_value_iter(values.as_slice())
+                    .collect::<Vec<&(dyn ToSql + Sync)>>()
+                    .as_slice(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
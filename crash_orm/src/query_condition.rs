@@ -1,18 +1,22 @@
 //! Contains the definition of query conditions.
 
 use std::marker::PhantomData;
-use std::sync::Arc;
-
-use tokio_postgres::types::ToSql;
 
+pub use array_condition::*;
 pub use bool_condition::*;
 pub use compare_condition::*;
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+pub use date_time_condition::*;
 pub use equal_condition::*;
+pub use full_text_condition::*;
 pub use in_condition::*;
+#[cfg(feature = "json")]
+pub use json_condition::*;
 pub use like_condition::*;
 pub use null_condition::*;
+pub use range_condition::*;
 
-use crate::prelude::{BoxedSql, Entity};
+use crate::prelude::{BoxedSql, Entity, Query, ResultMapping, SelectQueryType};
 
 mod null_condition;
 mod equal_condition;
@@ -20,6 +24,13 @@ mod like_condition;
 mod compare_condition;
 mod bool_condition;
 mod in_condition;
+mod array_condition;
+mod range_condition;
+mod full_text_condition;
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+mod date_time_condition;
+#[cfg(feature = "json")]
+mod json_condition;
 
 /// Query condition for entity
 pub struct QueryCondition<T: Entity> {
@@ -37,13 +48,6 @@ impl<T: Entity> QueryCondition<T> {
         }
     }
 
-    pub(crate) fn resolve(
-        self,
-        index: usize,
-    ) -> (String, Vec<Arc<Box<dyn ToSql + Send + Sync>>>, usize) {
-        self.boxed.resolve(index)
-    }
-
     /// Build AND condition from self and other
     pub fn and(mut self, other: QueryCondition<T>) -> QueryCondition<T> {
         self.boxed.modify(|v| format!("({v}) AND ({})", other.boxed.sql));
@@ -63,4 +67,22 @@ impl<T: Entity> QueryCondition<T> {
         self.boxed.modify(|v| format!("NOT({v})"));
         self
     }
+
+    /// Build EXISTS condition, which is true if the subquery returns at least one row.
+    ///
+    /// The subquery can reference columns of the outer query with [outer](crate::prelude::EntityColumn::outer).
+    pub fn exists<O: Entity, R: ResultMapping>(subquery: Query<O, R, SelectQueryType>) -> QueryCondition<T> {
+        let mut boxed = subquery.into_subquery_sql::<T>();
+        boxed.modify(|v| format!("EXISTS({v})"));
+        QueryCondition::new(boxed)
+    }
+
+    /// Build NOT EXISTS condition, which is true if the subquery returns no row.
+    ///
+    /// The subquery can reference columns of the outer query with [outer](crate::prelude::EntityColumn::outer).
+    pub fn not_exists<O: Entity, R: ResultMapping>(subquery: Query<O, R, SelectQueryType>) -> QueryCondition<T> {
+        let mut boxed = subquery.into_subquery_sql::<T>();
+        boxed.modify(|v| format!("NOT EXISTS({v})"));
+        QueryCondition::new(boxed)
+    }
 }
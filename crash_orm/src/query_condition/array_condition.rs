@@ -0,0 +1,56 @@
+use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition};
+
+/// Trait implementing array operator [QueryCondition]s for array columns.
+///
+/// Note, that [`Vec<u8>`] is stored as BYTEA and not as array.
+pub trait ArrayQueryColumn<T: ColumnType, U: Entity> {
+    /// Creates a condition checking if self contains all elements of other (`@>`)
+    fn contains(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if all elements of self are contained in other (`<@`)
+    fn contained_by(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self and other have any element in common (`&&`)
+    fn overlaps(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if any element of self equals value (`value = ANY(self)`)
+    fn any_equals(&self, value: impl IntoSql<T>) -> QueryCondition<U>;
+}
+
+impl<T: ColumnType + Clone, U: Entity, R: Column<Vec<T>, U>> ArrayQueryColumn<T, U> for R {
+    fn contains(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = other.into_boxed_sql();
+        boxed.modify(|v| format!("{v} @> {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn contained_by(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = other.into_boxed_sql();
+        boxed.modify(|v| format!("{v} <@ {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn overlaps(&self, other: impl IntoSql<Vec<T>>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = other.into_boxed_sql();
+        boxed.modify(|v| format!("{v} && {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn any_equals(&self, value: impl IntoSql<T>) -> QueryCondition<U> {
+        let mut boxed = value.into_boxed_sql();
+        let column_boxed = self.get_sql();
+        boxed.modify(|v| format!("{v} = ANY({})", column_boxed.sql));
+        boxed.values.extend(column_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+}
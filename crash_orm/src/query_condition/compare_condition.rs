@@ -1,4 +1,4 @@
-use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition};
+use crate::prelude::{Column, ColumnType, Entity, IntoSql, OrderedSqlType, QueryCondition};
 
 /// Trait implementing comparison operator [QueryCondition]
 pub trait CompareQueryColumn<T: ColumnType, U: Entity> {
@@ -30,8 +30,8 @@ pub trait CompareQueryColumn<T: ColumnType, U: Entity> {
 }
 
 macro_rules! impl_compare_entity_column {
-    ($column_type:ty) => {
-        impl<U: Entity, R: Column<$column_type, U>> CompareQueryColumn<$column_type, U> for R {
+    ([$($generics:tt)*] $column_type:ty) => {
+        impl<$($generics)* U: Entity, R: Column<$column_type, U>> CompareQueryColumn<$column_type, U> for R {
             fn greater_than(
                 &self,
                 other: impl IntoSql<$column_type>,
@@ -108,8 +108,12 @@ macro_rules! impl_compare_entity_column {
             }
         }
     };
+    ($column_type:ty) => {
+        impl_compare_entity_column!([] $column_type);
+    };
 }
 
+impl_compare_entity_column!([S: OrderedSqlType,] S);
 impl_compare_entity_column!(i8);
 impl_compare_entity_column!(i16);
 impl_compare_entity_column!(i32);
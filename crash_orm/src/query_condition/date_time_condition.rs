@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use crate::prelude::{CaseType, Column, ColumnType, DateTimeType, Entity, IntoSql, QueryCondition};
+
+/// Trait implementing date and time [QueryCondition]s for date and timestamp columns
+pub trait DateTimeQueryColumn<T: DateTimeType, D: ColumnType, U: Entity> {
+    /// Creates a condition checking if self is between now and the duration before now
+    fn within_last(&self, duration: Duration) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self is on the date.
+    ///
+    /// Timestamps with time zone are converted with the time zone of the session.
+    fn on_date(&self, date: impl IntoSql<D>) -> QueryCondition<U>;
+}
+
+macro_rules! impl_date_time_query_column {
+    ($column_type:ty, $date_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> DateTimeQueryColumn<$column_type, $date_type, U> for R {
+            fn within_last(&self, duration: Duration) -> QueryCondition<U> {
+                let mut boxed = self.get_sql();
+                let seconds = duration.as_secs_f64().into_boxed_sql();
+                let now = format!("CAST(NOW() AS {})", <$column_type as CaseType>::CAST_TYPE);
+                boxed.modify(|v| format!("{} BETWEEN {} - MAKE_INTERVAL(secs => {}) AND {}", v, now, seconds.sql, now));
+                boxed.values.extend(seconds.values);
+
+                QueryCondition::new(boxed)
+            }
+
+            fn on_date(&self, date: impl IntoSql<$date_type>) -> QueryCondition<U> {
+                let mut boxed = self.get_sql();
+                let date = date.into_boxed_sql();
+                boxed.modify(|v| format!("CAST({} AS date) = {}", v, date.sql));
+                boxed.values.extend(date.values);
+
+                QueryCondition::new(boxed)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "with-chrono")]
+impl_date_time_query_column!(chrono::NaiveDateTime, chrono::NaiveDate);
+#[cfg(feature = "with-chrono")]
+impl_date_time_query_column!(chrono::DateTime<chrono::Utc>, chrono::NaiveDate);
+#[cfg(feature = "with-chrono")]
+impl_date_time_query_column!(chrono::DateTime<chrono::Local>, chrono::NaiveDate);
+#[cfg(feature = "with-chrono")]
+impl_date_time_query_column!(chrono::DateTime<chrono::FixedOffset>, chrono::NaiveDate);
+#[cfg(feature = "with-chrono")]
+impl_date_time_query_column!(chrono::NaiveDate, chrono::NaiveDate);
+#[cfg(feature = "with-time")]
+impl_date_time_query_column!(time::PrimitiveDateTime, time::Date);
+#[cfg(feature = "with-time")]
+impl_date_time_query_column!(time::OffsetDateTime, time::Date);
+#[cfg(feature = "with-time")]
+impl_date_time_query_column!(time::Date, time::Date);
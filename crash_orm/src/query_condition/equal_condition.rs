@@ -1,5 +1,8 @@
+#[cfg(feature = "json")]
 use std::fmt::Debug;
-use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition, TypedJson};
+use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition, Range, RangeElement, SqlType};
+#[cfg(feature = "json")]
+use crate::prelude::TypedJson;
 
 /// Trait implementing equals operator [QueryCondition]
 pub trait EqualQueryColumn<T: ColumnType, U: Entity> {
@@ -11,8 +14,8 @@ pub trait EqualQueryColumn<T: ColumnType, U: Entity> {
 }
 
 macro_rules! impl_equal_entity_column {
-    ($column_type:ty) => {
-        impl<T: Entity, U: Column<$column_type, T>> EqualQueryColumn<$column_type, T> for U {
+    ([$($generics:tt)*] $column_type:ty) => {
+        impl<$($generics)* T: Entity, U: Column<$column_type, T>> EqualQueryColumn<$column_type, T> for U {
             fn equals(&self, other: impl IntoSql<$column_type>) -> QueryCondition<T> {
                 let mut boxed = self.get_sql();
                 let other_boxed = other.into_boxed_sql();
@@ -35,6 +38,9 @@ macro_rules! impl_equal_entity_column {
             }
         }
     };
+    ($column_type:ty) => {
+        impl_equal_entity_column!([] $column_type);
+    };
 }
 
 #[cfg(feature = "with-geo-types")]
@@ -84,7 +90,7 @@ impl<JSON: serde::Serialize + serde::de::DeserializeOwned + Debug + Clone + Send
     }
 }
 
-
+impl_equal_entity_column!([S: SqlType,] S);
 impl_equal_entity_column!(bool);
 impl_equal_entity_column!(i8);
 impl_equal_entity_column!(i16);
@@ -110,7 +116,8 @@ impl_equal_entity_column!(chrono::NaiveDate);
 impl_equal_entity_column!(chrono::NaiveTime);
 #[cfg(feature = "with-uuid")]
 impl_equal_entity_column!(uuid::Uuid);
-impl_equal_entity_column!(Vec<u8>);
+impl_equal_entity_column!([A: ColumnType + Clone,] Vec<A>);
+impl_equal_entity_column!([A: RangeElement,] Range<A>);
 #[cfg(feature = "json")]
 impl_equal_entity_column!(serde_json::Value);
 #[cfg(feature = "with-eui48")]
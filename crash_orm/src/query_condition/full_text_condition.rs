@@ -0,0 +1,33 @@
+use crate::prelude::{Column, ColumnType, Entity, QueryCondition, TsQuery, TsVector};
+
+/// Trait implementing full-text search [QueryCondition]s for [TsVector] and [String] columns
+pub trait FullTextQueryColumn<T: ColumnType, U: Entity> {
+    /// Creates a condition checking if self matches the query (`@@`).
+    ///
+    /// String columns are converted with `to_tsvector` using the configuration of the query.
+    fn matches(&self, query: &TsQuery) -> QueryCondition<U>;
+}
+
+impl<U: Entity, R: Column<TsVector, U>> FullTextQueryColumn<TsVector, U> for R {
+    fn matches(&self, query: &TsQuery) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let query_boxed = query.get_sql();
+        boxed.modify(|v| format!("{v} @@ {}", query_boxed.sql));
+        boxed.values.extend(query_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+}
+
+impl<U: Entity, R: Column<String, U>> FullTextQueryColumn<String, U> for R {
+    fn matches(&self, query: &TsQuery) -> QueryCondition<U> {
+        let mut boxed = query.get_config_sql();
+        let column_boxed = self.get_sql();
+        let query_boxed = query.get_sql();
+        boxed.modify(|v| format!("to_tsvector({v},{}) @@ {}", column_boxed.sql, query_boxed.sql));
+        boxed.values.extend(column_boxed.values);
+        boxed.values.extend(query_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+}
@@ -1,26 +1,43 @@
-use crate::prelude::{BoxedSql, Column, ColumnType, Entity, IntoSql, QueryCondition};
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, IntoSql, Query, QueryCondition, SelectQueryType, SingleResult, SqlType};
 
 /// Trait implementing IN operator [QueryCondition]
 pub trait InQueryColumn<T: ColumnType, U: Entity> {
-    /// Creates [QueryCondition::In] from self and other
+    /// Creates a condition checking if self is one of the values.
+    ///
+    /// The values are bound as a single array parameter (`= ANY($1)`).
+    /// If the values contain columns or NULL, they are listed instead (`IN (...)`).
     fn in_vec(&self, other: Vec<impl IntoSql<T>>) -> QueryCondition<U>;
 
-    /// Creates [QueryCondition::NotIn] from self and other
+    /// Creates a condition checking if self is none of the values (`<> ALL($1)`), see [in_vec](Self::in_vec).
     fn not_in_vec(&self, other: Vec<impl IntoSql<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self is in the results of the subquery (`IN (SELECT ...)`)
+    fn in_query<O: Entity>(&self, subquery: Query<O, SingleResult<T>, SelectQueryType>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self is not in the results of the subquery (`NOT IN (SELECT ...)`)
+    fn not_in_query<O: Entity>(&self, subquery: Query<O, SingleResult<T>, SelectQueryType>) -> QueryCondition<U>;
 }
 
 macro_rules! impl_in_entity_column {
-    ($column_type:ty) => {
-        impl<U: Entity, R: Column<$column_type, U>> InQueryColumn<$column_type, U> for R {
+    ([$($generics:tt)*] $column_type:ty) => {
+        impl<$($generics)* U: Entity, R: Column<$column_type, U>> InQueryColumn<$column_type, U> for R {
             fn in_vec(
                 &self,
                 other: Vec<impl IntoSql<$column_type>>,
             ) -> QueryCondition<U> {
                 let mut boxed = self.get_sql();
                 let other_boxed = other.iter().map(|i| i.into_boxed_sql()).collect::<Vec<BoxedSql>>();
-                boxed.modify(|v| format!("{v} IN ({})", other_boxed.iter().map(|i| &*i.sql).collect::<Vec<&str>>().join(",")));
-                for b in other_boxed {
-                    boxed.values.extend(b.values);
+                match BoxedSql::array(other_boxed.clone()) {
+                    Some(array) => {
+                        boxed.modify(|v| format!("{v} = ANY({})", array.sql));
+                        boxed.values.extend(array.values);
+                    }
+                    None => {
+                        boxed.modify(|v| format!("{v} IN ({})", other_boxed.iter().map(|i| &*i.sql).collect::<Vec<&str>>().join(",")));
+                        for b in other_boxed {
+                            boxed.values.extend(b.values);
+                        }
+                    }
                 }
 
                 QueryCondition::new(boxed)
@@ -32,23 +49,85 @@ macro_rules! impl_in_entity_column {
             ) -> QueryCondition<U> {
                 let mut boxed = self.get_sql();
                 let other_boxed = other.iter().map(|i| i.into_boxed_sql()).collect::<Vec<BoxedSql>>();
-                boxed.modify(|v| format!("{v} NOT IN ({})", other_boxed.iter().map(|i| &*i.sql).collect::<Vec<&str>>().join(",")));
-                for b in other_boxed {
-                    boxed.values.extend(b.values);
+                match BoxedSql::array(other_boxed.clone()) {
+                    Some(array) => {
+                        boxed.modify(|v| format!("{v} <> ALL({})", array.sql));
+                        boxed.values.extend(array.values);
+                    }
+                    None => {
+                        boxed.modify(|v| format!("{v} NOT IN ({})", other_boxed.iter().map(|i| &*i.sql).collect::<Vec<&str>>().join(",")));
+                        for b in other_boxed {
+                            boxed.values.extend(b.values);
+                        }
+                    }
                 }
 
                 QueryCondition::new(boxed)
             }
+
+            fn in_query<O: Entity>(
+                &self,
+                subquery: Query<O, SingleResult<$column_type>, SelectQueryType>,
+            ) -> QueryCondition<U> {
+                let mut boxed = self.get_sql();
+                let other_boxed = subquery.into_subquery_sql::<U>();
+                boxed.modify(|v| format!("{v} IN ({})", other_boxed.sql));
+                boxed.values.extend(other_boxed.values);
+
+                QueryCondition::new(boxed)
+            }
+
+            fn not_in_query<O: Entity>(
+                &self,
+                subquery: Query<O, SingleResult<$column_type>, SelectQueryType>,
+            ) -> QueryCondition<U> {
+                let mut boxed = self.get_sql();
+                let other_boxed = subquery.into_subquery_sql::<U>();
+                boxed.modify(|v| format!("{v} NOT IN ({})", other_boxed.sql));
+                boxed.values.extend(other_boxed.values);
+
+                QueryCondition::new(boxed)
+            }
         }
     };
+    ($column_type:ty) => {
+        impl_in_entity_column!([] $column_type);
+    };
 }
 
+impl_in_entity_column!([S: SqlType,] S);
+impl_in_entity_column!(bool);
 impl_in_entity_column!(i8);
 impl_in_entity_column!(i16);
 impl_in_entity_column!(i32);
 impl_in_entity_column!(i64);
 #[cfg(feature = "with-rust-decimal")]
 impl_in_entity_column!(rust_decimal::Decimal);
+impl_in_entity_column!(u32);
 impl_in_entity_column!(f32);
 impl_in_entity_column!(f64);
 impl_in_entity_column!(String);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::NaiveDateTime);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::DateTime<chrono::Utc>);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::DateTime<chrono::Local>);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::DateTime<chrono::FixedOffset>);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::NaiveDate);
+#[cfg(feature = "with-chrono")]
+impl_in_entity_column!(chrono::NaiveTime);
+#[cfg(feature = "with-uuid")]
+impl_in_entity_column!(uuid::Uuid);
+#[cfg(feature = "with-eui48")]
+impl_in_entity_column!(eui48::MacAddress);
+#[cfg(feature = "with-time")]
+impl_in_entity_column!(time::PrimitiveDateTime);
+#[cfg(feature = "with-time")]
+impl_in_entity_column!(time::OffsetDateTime);
+#[cfg(feature = "with-time")]
+impl_in_entity_column!(time::Date);
+#[cfg(feature = "with-time")]
+impl_in_entity_column!(time::Time);
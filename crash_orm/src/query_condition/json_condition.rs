@@ -0,0 +1,68 @@
+use serde_json::Value;
+
+use crate::prelude::{Column, Entity, IntoSql, JsonColumnType, QueryCondition};
+
+/// Trait implementing JSONB operator [QueryCondition]s for json columns
+pub trait JsonQueryColumn<T: JsonColumnType, U: Entity> {
+    /// Creates a condition checking if self contains the json other (`@>`)
+    fn contains(&self, other: impl IntoSql<Value>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if key exists as top-level key of self (`?`)
+    fn has_key(&self, key: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if any of the keys exists as top-level key of self (`?|`)
+    fn has_any_key(&self, keys: impl IntoSql<Vec<String>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if all keys exist as top-level keys of self (`?&`)
+    fn has_all_keys(&self, keys: impl IntoSql<Vec<String>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking the JSONPath predicate path against self (`@@`), e.g. `$.count > 2`
+    fn matches_json_path(&self, path: impl IntoSql<String>) -> QueryCondition<U>;
+}
+
+impl<T: JsonColumnType, U: Entity, R: Column<T, U>> JsonQueryColumn<T, U> for R {
+    fn contains(&self, other: impl IntoSql<Value>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = other.into_boxed_sql();
+        boxed.modify(|v| format!("{v} @> {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn has_key(&self, key: impl IntoSql<String>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = key.into_boxed_sql();
+        boxed.modify(|v| format!("{v} ? {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn has_any_key(&self, keys: impl IntoSql<Vec<String>>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = keys.into_boxed_sql();
+        boxed.modify(|v| format!("{v} ?| {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn has_all_keys(&self, keys: impl IntoSql<Vec<String>>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = keys.into_boxed_sql();
+        boxed.modify(|v| format!("{v} ?& {}", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    fn matches_json_path(&self, path: impl IntoSql<String>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = path.into_boxed_sql();
+        boxed.modify(|v| format!("{v} @@ CAST({}::text AS jsonpath)", other_boxed.sql));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+}
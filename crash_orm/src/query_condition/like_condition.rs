@@ -1,30 +1,100 @@
 use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition};
+#[cfg(feature = "with-citext")]
+use crate::prelude::Citext;
 
-/// Trait implementing like operator [QueryCondition]
+/// Escapes the wildcards `%` and `_` and the escape character `\` for a LIKE pattern.
+///
+/// The result matches the text literally, e.g. `format!("{}%", escape_like("50%_off"))` only matches texts starting with `50%_off`.
+pub fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// Trait implementing pattern matching [QueryCondition]s for string columns
 pub trait LikeQueryColumn<T: ColumnType, U: Entity> {
-    /// Creates [QueryCondition::Like] from self and other
+    /// Creates a condition checking if self matches the LIKE pattern
     fn like(&self, like: impl IntoSql<String>) -> QueryCondition<U>;
 
-    /// Creates [QueryCondition::NotLike] from self and other
+    /// Creates a condition checking if self doesn't match the LIKE pattern
     fn not_like(&self, like: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self matches the LIKE pattern case-insensitive (`ILIKE`)
+    fn ilike(&self, like: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self doesn't match the LIKE pattern case-insensitive (`NOT ILIKE`)
+    fn not_ilike(&self, like: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self matches the SQL regular expression (`SIMILAR TO`)
+    fn similar_to(&self, pattern: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self doesn't match the SQL regular expression (`NOT SIMILAR TO`)
+    fn not_similar_to(&self, pattern: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self matches the POSIX regular expression (`~`)
+    fn regex_match(&self, regex: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self matches the POSIX regular expression case-insensitive (`~*`)
+    fn regex_match_ignore_case(&self, regex: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self doesn't match the POSIX regular expression (`!~`)
+    fn not_regex_match(&self, regex: impl IntoSql<String>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self starts with the text.
+    ///
+    /// Wildcards in the text are escaped, see [escape_like].
+    fn starts_with(&self, text: &str) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self ends with the text.
+    ///
+    /// Wildcards in the text are escaped, see [escape_like].
+    fn ends_with(&self, text: &str) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self contains the text.
+    ///
+    /// Wildcards in the text are escaped, see [escape_like].
+    fn contains_text(&self, text: &str) -> QueryCondition<U>;
 }
 
-impl<U: Entity, R: Column<String, U>> LikeQueryColumn<String, U> for R {
-    fn like(&self, like: impl IntoSql<String>) -> QueryCondition<U> {
-        let mut boxed = self.get_sql();
-        let other_boxed = like.into_boxed_sql();
-        boxed.modify(|v| format!("{v} LIKE {}", other_boxed.sql));
-        boxed.values.extend(other_boxed.values);
+macro_rules! like_operator {
+    ($name:ident, $operator:literal) => {
+        fn $name(&self, other: impl IntoSql<String>) -> QueryCondition<U> {
+            let mut boxed = self.get_sql();
+            let other_boxed = other.into_boxed_sql();
+            boxed.modify(|v| format!("{} {} {}", v, $operator, other_boxed.sql));
+            boxed.values.extend(other_boxed.values);
 
-        QueryCondition::new(boxed)
-    }
+            QueryCondition::new(boxed)
+        }
+    };
+}
+
+macro_rules! impl_like_query_column {
+    ($column_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> LikeQueryColumn<$column_type, U> for R {
+            like_operator!(like, "LIKE");
+            like_operator!(not_like, "NOT LIKE");
+            like_operator!(ilike, "ILIKE");
+            like_operator!(not_ilike, "NOT ILIKE");
+            like_operator!(similar_to, "SIMILAR TO");
+            like_operator!(not_similar_to, "NOT SIMILAR TO");
+            like_operator!(regex_match, "~");
+            like_operator!(regex_match_ignore_case, "~*");
+            like_operator!(not_regex_match, "!~");
 
-    fn not_like(&self, like: impl IntoSql<String>) -> QueryCondition<U> {
-        let mut boxed = self.get_sql();
-        let other_boxed = like.into_boxed_sql();
-        boxed.modify(|v| format!("{v} NOT LIKE {}", other_boxed.sql));
-        boxed.values.extend(other_boxed.values);
+            fn starts_with(&self, text: &str) -> QueryCondition<U> {
+                LikeQueryColumn::<$column_type, U>::like(self, format!("{}%", escape_like(text)))
+            }
 
-        QueryCondition::new(boxed)
-    }
+            fn ends_with(&self, text: &str) -> QueryCondition<U> {
+                LikeQueryColumn::<$column_type, U>::like(self, format!("%{}", escape_like(text)))
+            }
+
+            fn contains_text(&self, text: &str) -> QueryCondition<U> {
+                LikeQueryColumn::<$column_type, U>::like(self, format!("%{}%", escape_like(text)))
+            }
+        }
+    };
 }
+
+impl_like_query_column!(String);
+#[cfg(feature = "with-citext")]
+impl_like_query_column!(Citext);
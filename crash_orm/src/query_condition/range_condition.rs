@@ -0,0 +1,52 @@
+use crate::prelude::{Column, Entity, IntoSql, QueryCondition, Range, RangeElement};
+
+/// Trait implementing range operator [QueryCondition]s for [Range] columns
+pub trait RangeQueryColumn<T: RangeElement, U: Entity> {
+    /// Creates a condition checking if self contains the value (`@>`)
+    fn contains_value(&self, value: impl IntoSql<T>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self contains the whole range other (`@>`)
+    fn contains_range(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self and other have any value in common (`&&`)
+    fn overlaps(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self and other are adjacent (`-|-`)
+    fn adjacent(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self is strictly left of other (`<<`)
+    fn strictly_left_of(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U>;
+
+    /// Creates a condition checking if self is strictly right of other (`>>`)
+    fn strictly_right_of(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U>;
+}
+
+macro_rules! range_operator {
+    ($name:ident, $operator:literal) => {
+        fn $name(&self, other: impl IntoSql<Range<T>>) -> QueryCondition<U> {
+            let mut boxed = self.get_sql();
+            let other_boxed = other.into_boxed_sql();
+            boxed.modify(|v| format!("{} {} {}", v, $operator, other_boxed.sql));
+            boxed.values.extend(other_boxed.values);
+
+            QueryCondition::new(boxed)
+        }
+    };
+}
+
+impl<T: RangeElement, U: Entity, R: Column<Range<T>, U>> RangeQueryColumn<T, U> for R {
+    fn contains_value(&self, value: impl IntoSql<T>) -> QueryCondition<U> {
+        let mut boxed = self.get_sql();
+        let other_boxed = value.into_boxed_sql();
+        boxed.modify(|v| format!("{v} @> CAST({} AS {})", other_boxed.sql, T::ELEMENT_TYPE));
+        boxed.values.extend(other_boxed.values);
+
+        QueryCondition::new(boxed)
+    }
+
+    range_operator!(contains_range, "@>");
+    range_operator!(overlaps, "&&");
+    range_operator!(adjacent, "-|-");
+    range_operator!(strictly_left_of, "<<");
+    range_operator!(strictly_right_of, ">>");
+}
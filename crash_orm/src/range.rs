@@ -0,0 +1,298 @@
+//! # Ranges
+//! [Range] maps to the range types of Postgres, e.g. a `Range<chrono::DateTime<Utc>>` is stored as `tstzrange`.
+//!
+//! | Rust type                                                  | Postgres type |
+//! |------------------------------------------------------------|---------------|
+//! | Range\<i32\>                                               | INT4RANGE     |
+//! | Range\<i64\>                                               | INT8RANGE     |
+//! | Range\<rust_decimal::Decimal\>                             | NUMRANGE      |
+//! | Range\<chrono::NaiveDate\>, Range\<time::Date\>            | DATERANGE     |
+//! | Range\<chrono::NaiveDateTime\>, Range\<time::PrimitiveDateTime\> | TSRANGE |
+//! | Range\<chrono::DateTime\>, Range\<time::OffsetDateTime\>   | TSTZRANGE     |
+//!
+//! Columns of a range support the [range operators](crate::prelude::RangeQueryColumn).
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleRangeEntity {
+//!     id: u32,
+//!     valid: Range<i32>,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # ExampleRangeEntity::create_table_if_not_exists(&conn).await.unwrap();
+//! ExampleRangeEntityCreate {
+//!     valid: Range::from(1..10),
+//! }.insert(&conn).await.unwrap();
+//!
+//! let results = ExampleRangeEntity::query()
+//!     .condition(ExampleRangeEntityColumn::VALID.contains_value(5))
+//!     .fetch(&conn).await.unwrap();
+//! # ExampleRangeEntity::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
+//!
+//! Postgres normalizes the bounds of discrete ranges, e.g. `[1,10]` of an `int4range` is returned as `[1,11)`.
+//!
+//! ## Exclusion Constraints
+//! The [Schema](crate::schema::Schema) derive can add an exclusion constraint to the table with `#[exclude(...)]`,
+//! e.g. to prevent overlapping bookings of the same room:
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # #[cfg(feature = "with-chrono")]
+//! use chrono::{DateTime, Utc};
+//!
+//! # #[cfg(feature = "with-chrono")]
+//! #[derive(Entity, Debug, Schema)]
+//! #[exclude(using = "gist", columns(room = "=", during = "&&"))]
+//! struct ExampleBooking {
+//!     id: u32,
+//!     room: i32,
+//!     during: Range<DateTime<Utc>>,
+//! }
+//! ```
+//!
+//! `using` defaults to `gist`, the constraint can be named with `name = "..."`.
+//! Note, that `=` on scalar columns in a gist index requires the extension `btree_gist`.
+//! Violations are returned as [Error::ExclusionViolation](crate::Error::ExclusionViolation).
+
+use std::error::Error;
+use std::ops::{Bound, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+use crate::prelude::ColumnType;
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LOWER_INCLUSIVE: u8 = 0x02;
+const RANGE_UPPER_INCLUSIVE: u8 = 0x04;
+const RANGE_LOWER_UNBOUNDED: u8 = 0x08;
+const RANGE_UPPER_UNBOUNDED: u8 = 0x10;
+
+/// Range of values, stored as range type of Postgres, see [here](crate::range).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range<T: RangeElement> {
+    bounds: Option<(Bound<T>, Bound<T>)>,
+}
+
+impl<T: RangeElement> Range<T> {
+    /// Creates a range from a lower and upper bound
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        Self {
+            bounds: Some((lower, upper)),
+        }
+    }
+
+    /// Creates an empty range
+    pub fn empty() -> Self {
+        Self {
+            bounds: None,
+        }
+    }
+
+    /// Returns true, if this range is empty
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_none()
+    }
+
+    /// Returns the lower bound, [None] if this range is empty
+    pub fn lower(&self) -> Option<&Bound<T>> {
+        self.bounds.as_ref().map(|(lower, _)| lower)
+    }
+
+    /// Returns the upper bound, [None] if this range is empty
+    pub fn upper(&self) -> Option<&Bound<T>> {
+        self.bounds.as_ref().map(|(_, upper)| upper)
+    }
+}
+
+impl<T: RangeElement> From<std::ops::Range<T>> for Range<T> {
+    fn from(value: std::ops::Range<T>) -> Self {
+        Self::new(Bound::Included(value.start), Bound::Excluded(value.end))
+    }
+}
+
+impl<T: RangeElement> From<RangeInclusive<T>> for Range<T> {
+    fn from(value: RangeInclusive<T>) -> Self {
+        let (start, end) = value.into_inner();
+        Self::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T: RangeElement> From<RangeFrom<T>> for Range<T> {
+    fn from(value: RangeFrom<T>) -> Self {
+        Self::new(Bound::Included(value.start), Bound::Unbounded)
+    }
+}
+
+impl<T: RangeElement> From<RangeTo<T>> for Range<T> {
+    fn from(value: RangeTo<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Excluded(value.end))
+    }
+}
+
+impl<T: RangeElement> From<RangeToInclusive<T>> for Range<T> {
+    fn from(value: RangeToInclusive<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(value.end))
+    }
+}
+
+impl<T: RangeElement> From<RangeFull> for Range<T> {
+    fn from(_: RangeFull) -> Self {
+        Self::new(Bound::Unbounded, Bound::Unbounded)
+    }
+}
+
+/// Trait for all types which can be the element of a [Range].
+pub trait RangeElement: ColumnType + Clone {
+    /// Name of the range type in Postgres, e.g. `int4range`
+    const RANGE_TYPE: &'static str;
+
+    /// Name of the element type in Postgres, e.g. `int4`
+    const ELEMENT_TYPE: &'static str;
+}
+
+macro_rules! range_element {
+    ($element_type:ty, $range_type:literal, $postgres_element_type:literal) => {
+        impl RangeElement for $element_type {
+            const RANGE_TYPE: &'static str = $range_type;
+            const ELEMENT_TYPE: &'static str = $postgres_element_type;
+        }
+    };
+}
+
+range_element!(i32, "int4range", "int4");
+range_element!(i64, "int8range", "int8");
+#[cfg(feature = "with-rust-decimal")]
+range_element!(rust_decimal::Decimal, "numrange", "numeric");
+#[cfg(feature = "with-chrono")]
+range_element!(chrono::NaiveDate, "daterange", "date");
+#[cfg(feature = "with-chrono")]
+range_element!(chrono::NaiveDateTime, "tsrange", "timestamp");
+#[cfg(feature = "with-chrono")]
+range_element!(chrono::DateTime<chrono::Utc>, "tstzrange", "timestamptz");
+#[cfg(feature = "with-chrono")]
+range_element!(chrono::DateTime<chrono::Local>, "tstzrange", "timestamptz");
+#[cfg(feature = "with-chrono")]
+range_element!(chrono::DateTime<chrono::FixedOffset>, "tstzrange", "timestamptz");
+#[cfg(feature = "with-time")]
+range_element!(time::Date, "daterange", "date");
+#[cfg(feature = "with-time")]
+range_element!(time::PrimitiveDateTime, "tsrange", "timestamp");
+#[cfg(feature = "with-time")]
+range_element!(time::OffsetDateTime, "tstzrange", "timestamptz");
+
+fn element_type(ty: &Type) -> Option<&Type> {
+    match ty.kind() {
+        Kind::Range(element_type) => Some(element_type),
+        _ => None,
+    }
+}
+
+fn write_bound<T: RangeElement>(
+    bound: &Bound<T>,
+    element_type: &Type,
+    out: &mut BytesMut,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let value = match bound {
+        Bound::Included(value) | Bound::Excluded(value) => value,
+        Bound::Unbounded => return Ok(()),
+    };
+
+    let length_position = out.len();
+    out.extend_from_slice(&[0; 4]);
+    if let IsNull::Yes = value.to_sql(element_type, out)? {
+        return Err("range bounds must not be null".into());
+    }
+
+    let length = i32::try_from(out.len() - length_position - 4)?;
+    out[length_position..length_position + 4].copy_from_slice(&length.to_be_bytes());
+
+    Ok(())
+}
+
+fn read_bound<T: RangeElement>(
+    raw: &mut &[u8],
+    element_type: &Type,
+    unbounded: bool,
+    inclusive: bool,
+) -> Result<Bound<T>, Box<dyn Error + Sync + Send>> {
+    if unbounded {
+        return Ok(Bound::Unbounded);
+    }
+
+    if raw.len() < 4 {
+        return Err("invalid range bound".into());
+    }
+    let length = usize::try_from(i32::from_be_bytes(raw[..4].try_into()?))?;
+    if raw.len() < 4 + length {
+        return Err("invalid range bound".into());
+    }
+
+    let value = T::from_sql(element_type, &raw[4..4 + length])?;
+    *raw = &raw[4 + length..];
+
+    Ok(if inclusive { Bound::Included(value) } else { Bound::Excluded(value) })
+}
+
+impl<T: RangeElement> ToSql for Range<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let element_type = element_type(ty).ok_or("expected range type")?;
+
+        let Some((lower, upper)) = &self.bounds else {
+            out.extend_from_slice(&[RANGE_EMPTY]);
+            return Ok(IsNull::No);
+        };
+
+        let mut flags = 0;
+        match lower {
+            Bound::Included(_) => flags |= RANGE_LOWER_INCLUSIVE,
+            Bound::Excluded(_) => {}
+            Bound::Unbounded => flags |= RANGE_LOWER_UNBOUNDED,
+        }
+        match upper {
+            Bound::Included(_) => flags |= RANGE_UPPER_INCLUSIVE,
+            Bound::Excluded(_) => {}
+            Bound::Unbounded => flags |= RANGE_UPPER_UNBOUNDED,
+        }
+
+        out.extend_from_slice(&[flags]);
+        write_bound(lower, element_type, out)?;
+        write_bound(upper, element_type, out)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        element_type(ty).is_some_and(|element_type| <T as ToSql>::accepts(element_type))
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a, T: RangeElement> FromSql<'a> for Range<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let element_type = element_type(ty).ok_or("expected range type")?;
+        let (&flags, mut raw) = raw.split_first().ok_or("invalid range")?;
+
+        if flags & RANGE_EMPTY != 0 {
+            return Ok(Self::empty());
+        }
+
+        let lower = read_bound(&mut raw, element_type, flags & RANGE_LOWER_UNBOUNDED != 0, flags & RANGE_LOWER_INCLUSIVE != 0)?;
+        let upper = read_bound(&mut raw, element_type, flags & RANGE_UPPER_UNBOUNDED != 0, flags & RANGE_UPPER_INCLUSIVE != 0)?;
+
+        Ok(Self::new(lower, upper))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        element_type(ty).is_some_and(|element_type| <T as FromSql>::accepts(element_type))
+    }
+}
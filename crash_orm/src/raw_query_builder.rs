@@ -114,8 +114,8 @@ impl RawQueryBuilder {
     pub async fn query_many<R: ResultMapping>(self, conn: &impl DatabaseConnection) -> crate::Result<Vec<R>> {
         let (query, values) = self.build();
         
-        Ok(conn.query_many(&*query, slice_query_value_iter(values.as_slice())
+        conn.query_many(&*query, slice_query_value_iter(values.as_slice())
             .collect::<Vec<&(dyn ToSql + Sync)>>()
-            .as_slice()).await?.into_iter().map(|r| R::from_row(r).unwrap()).collect())
+            .as_slice()).await?.into_iter().map(|r| R::from_row(r).map_err(|e| e.with_statement(&query))).collect()
     }
 }
@@ -1,9 +1,56 @@
 //! Contains the trait for mapping a [Row] into an object.
 //!
 //! Also contains the wrapper struct [SingleResult] for easy parsing of a single column result.
+//!
+//! Tuples of up to 12 [ColumnType]s map the selected columns in order, e.g. `select_query::<(String, i64)>`.
+//! With the `json` feature, a row can also be mapped into a [JsonRow] or deserialized with `DeserializedResult`.
+//!
+//! If a column can't be converted, [from_row](ResultMapping::from_row) returns [Error::RowMapping](crate::Error::RowMapping)
+//! containing the column name, the expected and the actual type.
+//! Use [Query::lenient](crate::query::Query::lenient) to skip those rows instead.
+//!
+//! ## Derive
+//! [ResultMapping](crate::derive::ResultMapping) can be derived for structs.
+//! Fields are mapped by the name of the column, so the order of the selected columns does not matter.
+//!
+//! - `#[column(name = "...")]` maps the field to a column with a different name
+//! - `#[default]` uses the [Default] value if the column is missing in the result
+//! - `#[flatten]` maps a nested [ResultMapping] from the same row, e.g. a struct deriving it or a tuple
+//!
+//! Virtual columns are named by Postgres, e.g. `sum` for `SUM(number)`.
+//! Use [alias](crate::virtual_column::VirtualColumn::alias) to choose the name of a selected virtual column.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ResultMappingExample {
+//!     id: u32,
+//!     number: i32,
+//! }
+//!
+//! #[derive(ResultMapping)]
+//! struct Totals {
+//!     #[column(name = "total")]
+//!     sum: i64,
+//!     #[default]
+//!     count: i64,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! # ResultMappingExample::create_table_if_not_exists(&conn).await.unwrap();
+//! let totals = ResultMappingExample::select_query::<Totals>(&[
+//!     &ResultMappingExampleColumn::NUMBER.sum(false).alias("total"),
+//! ]).fetch_single(&conn).await;
+//! # });
+//! ```
 
 use crate::prelude::ColumnType;
 use postgres::Row;
+use postgres::types::{FromSql, WasNull};
+use std::error::Error;
 use std::ops::{Deref, DerefMut};
 
 /// This trait parses the result of a single row in a query.
@@ -11,18 +58,75 @@ use std::ops::{Deref, DerefMut};
 /// Required for [Entity::select_query] generic type.
 pub trait ResultMapping {
     /// Parses Self from a [Row].
-    fn from_row(row: Row) -> Option<Self> where Self: Sized;
+    fn from_row(row: Row) -> crate::Result<Self> where Self: Sized;
+
+    /// Parses Self from a borrowed [Row], used to map fields with `#[flatten]`.
+    ///
+    /// The default implementation clones the row and calls [from_row](Self::from_row).
+    fn from_row_ref(row: &Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Self::from_row(row.clone())
+    }
 }
 
 impl ResultMapping for Row {
-    fn from_row(row: Row) -> Option<Self>
+    fn from_row(row: Row) -> crate::Result<Self>
     where
         Self: Sized
     {
-        Some(row)
+        Ok(row)
     }
 }
 
+/// Reads the column with the name `name` of the row.
+///
+/// Returns [Error::RowMapping](crate::Error::RowMapping) if the column does not exist or can't be converted to `T`.
+pub fn try_get_named_column<'a, T: FromSql<'a>>(row: &'a Row, name: &str) -> crate::Result<T> {
+    let Some(index) = row.columns().iter().position(|c| c.name() == name) else {
+        return Err(crate::Error::RowMapping {
+            column: name.to_string(),
+            expected: std::any::type_name::<T>().to_string(),
+            actual: String::from("missing"),
+            statement: String::new(),
+        });
+    };
+
+    try_get_column(row, index)
+}
+
+/// Reads the column with the name `name` of the row, returns the default value of `T` if the column does not exist.
+///
+/// Returns [Error::RowMapping](crate::Error::RowMapping) if the column can't be converted to `T`.
+pub fn try_get_named_column_or_default<'a, T: FromSql<'a> + Default>(row: &'a Row, name: &str) -> crate::Result<T> {
+    match row.columns().iter().position(|c| c.name() == name) {
+        Some(index) => try_get_column(row, index),
+        None => Ok(T::default()),
+    }
+}
+
+/// Reads the column at `index` of the row.
+///
+/// Returns [Error::RowMapping](crate::Error::RowMapping) if the column does not exist or can't be converted to `T`.
+pub fn try_get_column<'a, T: FromSql<'a>>(row: &'a Row, index: usize) -> crate::Result<T> {
+    row.try_get(index).map_err(|e| {
+        let column = row.columns().get(index);
+        let actual = match column {
+            None => String::from("missing"),
+            Some(_) if e.source().is_some_and(|e| e.is::<WasNull>()) => String::from("NULL"),
+            Some(column) => column.type_().to_string(),
+        };
+
+        crate::Error::RowMapping {
+            column: column.map(|c| c.name().to_string()).unwrap_or_else(|| index.to_string()),
+            expected: std::any::type_name::<T>().to_string(),
+            actual,
+            statement: String::new(),
+        }
+    })
+}
+
 /// Wrapper struct which holds a single element for result mapping from a select query.
 ///
 /// Implements [ResultMapping], supports all types with [ColumnType].
@@ -45,21 +149,199 @@ impl<T: ColumnType> DerefMut for SingleResult<T> {
 }
 
 impl<T: ColumnType> ResultMapping for SingleResult<T> {
-    fn from_row(row: Row) -> Option<Self>
+    fn from_row(row: Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Self::from_row_ref(&row)
+    }
+
+    fn from_row_ref(row: &Row) -> crate::Result<Self>
     where
         Self: Sized
     {
-        Some(SingleResult {
-            inner: row.try_get::<_, T>(0).ok()?,
+        Ok(SingleResult {
+            inner: try_get_column(row, 0)?,
         })
     }
 }
 
 impl ResultMapping for () {
-    fn from_row(_row: Row) -> Option<Self>
+    fn from_row(_row: Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Ok(())
+    }
+
+    fn from_row_ref(_row: &Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Ok(())
+    }
+}
+
+macro_rules! impl_tuple_result_mapping {
+    ($($type:ident: $index:tt),+) => {
+        impl<$($type: ColumnType),+> ResultMapping for ($($type,)+) {
+            fn from_row(row: Row) -> crate::Result<Self>
+            where
+                Self: Sized
+            {
+                Self::from_row_ref(&row)
+            }
+
+            fn from_row_ref(row: &Row) -> crate::Result<Self>
+            where
+                Self: Sized
+            {
+                Ok(($(try_get_column::<$type>(row, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple_result_mapping!(A: 0);
+impl_tuple_result_mapping!(A: 0, B: 1);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+impl_tuple_result_mapping!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
+/// A row as json object, mapping the column names to their values.
+#[cfg(feature = "json")]
+pub type JsonRow = serde_json::Map<String, serde_json::Value>;
+
+#[cfg(feature = "json")]
+impl ResultMapping for JsonRow {
+    fn from_row(row: Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        Self::from_row_ref(&row)
+    }
+
+    fn from_row_ref(row: &Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        let mut map = JsonRow::new();
+
+        for (index, column) in row.columns().iter().enumerate() {
+            map.insert(column.name().to_string(), column_to_json(row, index)?);
+        }
+
+        Ok(map)
+    }
+}
+
+/// Converts the column at `index` of the row into a json value.
+#[cfg(feature = "json")]
+fn column_to_json(row: &Row, index: usize) -> crate::Result<serde_json::Value> {
+    use postgres::types::Type;
+    use serde_json::Value;
+
+    fn get<'a, T: FromSql<'a> + Into<Value>>(row: &'a Row, index: usize) -> crate::Result<Value> {
+        Ok(try_get_column::<Option<T>>(row, index)?.map(|v| v.into()).unwrap_or(Value::Null))
+    }
+
+    let ty = row.columns()[index].type_().clone();
+    match ty {
+        Type::BOOL => get::<bool>(row, index),
+        Type::CHAR => get::<i8>(row, index),
+        Type::INT2 => get::<i16>(row, index),
+        Type::INT4 => get::<i32>(row, index),
+        Type::INT8 => get::<i64>(row, index),
+        Type::OID => get::<u32>(row, index),
+        Type::FLOAT4 => get::<f32>(row, index),
+        Type::FLOAT8 => get::<f64>(row, index),
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN => get::<String>(row, index),
+        Type::JSON | Type::JSONB => get::<Value>(row, index),
+        #[cfg(feature = "with-chrono")]
+        Type::TIMESTAMPTZ => Ok(try_get_column::<Option<chrono::DateTime<chrono::Utc>>>(row, index)?
+            .map(|v| Value::String(v.to_rfc3339())).unwrap_or(Value::Null)),
+        #[cfg(feature = "with-chrono")]
+        Type::TIMESTAMP => Ok(try_get_column::<Option<chrono::NaiveDateTime>>(row, index)?
+            .map(|v| Value::String(v.format("%Y-%m-%dT%H:%M:%S%.f").to_string())).unwrap_or(Value::Null)),
+        #[cfg(feature = "with-chrono")]
+        Type::DATE => Ok(try_get_column::<Option<chrono::NaiveDate>>(row, index)?
+            .map(|v| Value::String(v.to_string())).unwrap_or(Value::Null)),
+        #[cfg(feature = "with-chrono")]
+        Type::TIME => Ok(try_get_column::<Option<chrono::NaiveTime>>(row, index)?
+            .map(|v| Value::String(v.to_string())).unwrap_or(Value::Null)),
+        #[cfg(feature = "with-uuid")]
+        Type::UUID => Ok(try_get_column::<Option<uuid::Uuid>>(row, index)?
+            .map(|v| Value::String(v.to_string())).unwrap_or(Value::Null)),
+        #[cfg(feature = "with-rust-decimal")]
+        Type::NUMERIC => Ok(try_get_column::<Option<rust_decimal::Decimal>>(row, index)?
+            .map(|v| Value::String(v.to_string())).unwrap_or(Value::Null)),
+        ty => Err(crate::Error::RowMapping {
+            column: row.columns()[index].name().to_string(),
+            expected: String::from("serde_json::Value"),
+            actual: ty.to_string(),
+            statement: String::new(),
+        }),
+    }
+}
+
+/// Wrapper struct which deserializes a row with [serde].
+///
+/// The row is converted into a [JsonRow] first, which is then deserialized into `T`.
+/// This is useful for ad-hoc queries, where a struct deriving [ResultMapping](crate::derive::ResultMapping) is not worth it.
+#[cfg(feature = "json")]
+pub struct DeserializedResult<T: serde::de::DeserializeOwned> {
+    inner: T,
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> DeserializedResult<T> {
+    /// Returns the deserialized value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> Deref for DeserializedResult<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> DerefMut for DeserializedResult<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> ResultMapping for DeserializedResult<T> {
+    fn from_row(row: Row) -> crate::Result<Self>
     where
         Self: Sized
     {
-        Some(())
+        Self::from_row_ref(&row)
+    }
+
+    fn from_row_ref(row: &Row) -> crate::Result<Self>
+    where
+        Self: Sized
+    {
+        let map = JsonRow::from_row_ref(row)?;
+
+        Ok(DeserializedResult {
+            inner: serde_json::from_value(serde_json::Value::Object(map))
+                .map_err(|e| crate::Error::String(format!("Failed to deserialize row into {}: {}", std::any::type_name::<T>(), e)))?,
+        })
     }
 }
@@ -84,6 +84,26 @@
 //! TestEntity::truncate_table(&conn).await.unwrap();
 //! # });
 //! ```
+//!
+//! ## Diff Table
+//! Returns the statements required to change the table in the database to match the entity, without executing them.
+//! Register your entities with [get_entities](crate::migration::CrashOrmMigrationManager::get_entities)
+//! to print the diff of all of them with `schema diff` of crash_orm_cli.
+//!
+//! ```rust
+//! use crash_orm::prelude::*;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct TestEntity {
+//! #    id: u32,
+//! # }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! let statements: Vec<String> = TestEntity::diff_table(&conn).await.unwrap();
+//! # });
+//! ```
 
 pub use column_definition::*;
 pub use schema::*;
@@ -1,5 +1,6 @@
-use postgres::types::Type;
+use postgres::types::{Kind, Type};
 
+use crate::connection::DatabaseConnection;
 use crate::schema::foreign_key::ForeignKey;
 
 /// Struct describing a column in a table
@@ -54,6 +55,22 @@ impl ColumnDefinition {
         }
     }
 
+    /// Resolves the name of a type, e.g. `int4`, `varchar(254)` or `text[]`, to its Postgres type.
+    ///
+    /// Type modifiers, like the length of a varchar, are ignored.
+    pub async fn resolve_type(conn: &impl DatabaseConnection, type_name: &str) -> crate::Result<Type> {
+        let row = conn.query_single(
+            "SELECT t.oid, t.typname, n.nspname FROM pg_catalog.pg_type t JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace WHERE t.oid = to_regtype($1)",
+            &[&type_name],
+        ).await?;
+
+        let Some(row) = row else {
+            return Err(crate::Error::String(format!("Type {} does not exist", type_name)));
+        };
+
+        Ok(type_from_database(row.get(0), row.get(1), row.get(2)))
+    }
+
     /// Rename the column
     pub fn rename(&mut self, new_name: &'static str) -> &mut ColumnDefinition {
         self.name = new_name.to_string();
@@ -106,4 +123,8 @@ impl ColumnDefinition {
         });
         self
     }
-}
\ No newline at end of file
+}
+/// Returns the built-in type with the oid or, for types like enums or extensions, a simple type with the name.
+pub(crate) fn type_from_database(oid: u32, name: String, schema: String) -> Type {
+    Type::from_oid(oid).unwrap_or_else(|| Type::new(name, oid, Kind::Simple, schema))
+}
@@ -1,6 +1,10 @@
+use std::future::Future;
+use std::pin::Pin;
+
 use async_trait::async_trait;
 
-use crate::connection::DatabaseConnection;
+use crate::connection::{DatabaseConnection, RecordingDatabaseConnection};
+use crate::schema::TableDefinition;
 
 /// Trait implementing functions to modify the table itself in the database.
 ///
@@ -42,4 +46,65 @@ pub trait Schema {
 
         Ok(())
     }
+
+    /// Returns the definition of the table based on the provided struct.
+    ///
+    /// The column types are resolved with the connection, so custom types must exist in the database.
+    async fn table_definition(connection: &impl DatabaseConnection) -> crate::Result<TableDefinition>;
+
+    /// Returns the statements required to change the table in the database to match the provided struct.
+    ///
+    /// If the table does not exist, these are the statements of [create_table](Self::create_table).
+    /// Otherwise, columns are added or dropped and the type, nullability and primary key of existing columns are changed.
+    /// Type modifiers, defaults, foreign keys, indexes and constraints are not compared.
+    async fn diff_table<C: RecordingDatabaseConnection>(connection: &C) -> crate::Result<Vec<String>> {
+        if !Self::table_exists(connection).await? {
+            let recording = connection.recording();
+            Self::create_table(&recording).await?;
+
+            return Ok(recording.take_recorded_statements().into_iter().map(|statement| statement.statement).collect());
+        }
+
+        let definition = Self::table_definition(connection).await?;
+
+        Ok(TableDefinition::load_from_database(connection, &definition.name).await?
+            .change_columns(definition)
+            .diff_sql())
+    }
+}
+
+type DiffTableFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<Vec<String>>> + Send + 'a>>;
+
+/// An entity whose table is compared with the database by
+/// [schema_diff](crate::migration::CrashOrmMigrationManager::schema_diff).
+///
+/// ```
+/// use crash_orm::prelude::*;
+///
+/// # #[derive(Entity, Debug, Schema)]
+/// # struct TestEntity {
+/// #    id: u32,
+/// # }
+/// let entities: Vec<EntitySchema> = vec![EntitySchema::of::<TestEntity>()];
+/// ```
+pub struct EntitySchema<C = crate::connection::CrashOrmDatabaseConnection> {
+    diff_table: for<'a> fn(&'a C) -> DiffTableFuture<'a>,
+}
+
+impl<C: RecordingDatabaseConnection> EntitySchema<C> {
+    /// Creates the entity schema of `S`.
+    pub fn of<S: Schema + Send + 'static>() -> Self {
+        fn diff_table<S: Schema + Send + 'static, C: RecordingDatabaseConnection>(connection: &C) -> DiffTableFuture<'_> {
+            S::diff_table(connection)
+        }
+
+        Self {
+            diff_table: diff_table::<S, C>,
+        }
+    }
+
+    /// See [Schema::diff_table]
+    pub async fn diff_table(&self, connection: &C) -> crate::Result<Vec<String>> {
+        (self.diff_table)(connection).await
+    }
 }
@@ -1,15 +1,14 @@
 use std::collections::HashSet;
 
-use postgres::types::Type;
-
 use crate::prelude::{ColumnDefinition, DatabaseConnection};
+use crate::schema::column_definition::type_from_database;
 use crate::schema::foreign_key::ForeignKey;
 
 /// Struct describing a table in the database
 #[derive(Clone, Debug)]
 pub struct TableDefinition {
     old_name: Option<String>,
-    name: String,
+    pub(crate) name: String,
     columns: Vec<ColumnDefinition>,
     dropped_columns: Vec<String>,
     old_primary_keys: Option<Vec<String>>,
@@ -57,7 +56,7 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
             .collect::<Vec<ForeignKey>>();
 
         let rows = conn.query_many(
-            "SELECT column_name, is_nullable, (SELECT oid FROM pg_catalog.pg_type pg_type WHERE pg_type.typname = c.udt_name), column_default FROM information_schema.columns c WHERE table_schema = 'public' AND table_name = $1",
+            "SELECT column_name, is_nullable, (SELECT oid FROM pg_catalog.pg_type pg_type WHERE pg_type.typname = c.udt_name), column_default, udt_name, udt_schema FROM information_schema.columns c WHERE table_schema = 'public' AND table_name = $1",
             &[&name.to_string()],
         ).await?;
 
@@ -65,8 +64,7 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
         for column_row in rows {
             let name: String = column_row.get(0);
             let is_nullable: String = column_row.get(1);
-            let sql_type_id: u32 = column_row.get(2);
-            let sql_type = Type::from_oid(sql_type_id).unwrap();
+            let sql_type = type_from_database(column_row.get(2), column_row.get(4), column_row.get(5));
             let is_primary = primary_keys.contains(&name);
             let default_value: Option<String> = column_row.get(3);
             let foreign_key = foreign_keys.iter().find(|v| v.src_field == name).map(|v| v.clone());
@@ -130,6 +128,33 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
         Ok(self)
     }
 
+    /// Changes the columns of this definition loaded from the database to the columns of `target`.
+    ///
+    /// Columns missing in `target` are dropped, new columns are added and the type, nullability and primary key
+    /// of existing columns are taken from `target`. Defaults and foreign keys of existing columns are kept.
+    pub(crate) fn change_columns(mut self, target: TableDefinition) -> TableDefinition {
+        for column in std::mem::take(&mut self.columns) {
+            if target.columns.iter().any(|target_column| target_column.name == column.name) {
+                self.columns.push(column);
+            } else {
+                self.dropped_columns.push(column.name);
+            }
+        }
+
+        for target_column in target.columns {
+            match self.columns.iter_mut().find(|column| column.name == target_column.name) {
+                Some(column) => {
+                    column.sql_type = target_column.sql_type;
+                    column.nullable = target_column.nullable;
+                    column.primary_key = target_column.primary_key;
+                }
+                None => self.columns.push(target_column),
+            }
+        }
+
+        self
+    }
+
     /// Returns the diff in sql statements
     pub fn diff_sql(self) -> Vec<String> {
         let mut queries = vec![];
@@ -164,6 +189,10 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
                         string.push_str(" NOT NULL");
                     }
                     alters.push(string);
+
+                    if column.primary_key {
+                        primary_keys.push(column.name);
+                    }
                 } else {
                     let old_name = column.old_name.unwrap();
                     if old_name != column.name {
@@ -177,7 +206,7 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
 
                     let old_nullable = column.old_nullable.unwrap();
                     if old_nullable != column.nullable {
-                        if !column.nullable {
+                        if column.nullable {
                             alters.push(format!("ALTER COLUMN {} DROP NOT NULL", column.name));
                         } else {
                             alters.push(format!("ALTER COLUMN {} SET NOT NULL", column.name));
@@ -222,12 +251,14 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
                     alters.push(format!("DROP CONSTRAINT {}_pkey", self.name));
                 }
 
-                alters.push(format!("ADD PRIMARY KEY ({})", primary_keys.join(",")))
+                if !primary_keys.is_empty() {
+                    alters.push(format!("ADD PRIMARY KEY ({})", primary_keys.join(",")));
+                }
             }
 
-            let query = format!("ALTER TABLE {} {}", self.name, alters.join(","));
-
-            queries.push(query);
+            if !alters.is_empty() {
+                queries.push(format!("ALTER TABLE {} {}", self.name, alters.join(",")));
+            }
         } else {
             let mut columns = vec![];
             let mut primary_columns = vec![];
@@ -270,6 +301,8 @@ WHERE r.conrelid = '{}'::regclass AND r.contype = 'f'", name);
     }
 
     /// Apply the changes to the database
+    ///
+    /// Pass a connection in [capture mode](crate::prelude::RecordingDatabaseConnection::recording) to record the statements instead.
     pub async fn apply(self, conn: &impl DatabaseConnection) -> crate::Result<()> {
         let queries = self.diff_sql();
 
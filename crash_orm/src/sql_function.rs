@@ -0,0 +1,180 @@
+//! # Custom functions and operators
+//! Functions and operators of Postgres or of extensions, which are not covered by this crate,
+//! are declared with [sql_function!](crate::sql_function!) and [sql_operator!](crate::sql_operator!).
+//!
+//! Both generate a function, which takes the arguments as values or columns
+//! and returns a [VirtualColumn] with the declared return type.
+//! Without a return type, the result is a [QueryCondition](crate::prelude::QueryCondition).
+//! Values are bound as parameters like in all other columns and conditions.
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//!
+//! # #[derive(Entity, Debug, Schema)]
+//! # struct Product {
+//! #    id: u32,
+//! #    name: String,
+//! # }
+//! // requires CREATE EXTENSION pg_trgm
+//! sql_function!(similarity(String, String) -> f32);
+//! sql_operator!(trigram_match = "%"(String, String));
+//!
+//! // SELECT name, similarity(name,$1) FROM public.product WHERE (name % $2)
+//! let query = Product::select_query::<(String, f32)>(&[&ProductColumn::NAME, &similarity(ProductColumn::NAME, "shoe")])
+//!     .condition(trigram_match(ProductColumn::NAME, "shoe"))
+//!     .order(&similarity(ProductColumn::NAME, "shoe"), OrderDirection::DESC);
+//! ```
+//!
+//! The entity of the generated function is inferred from the query.
+//! If it can't be inferred, e.g. if all arguments are values, it has to be specified: `similarity::<Product>("a", "b")`.
+//!
+//! The name of the function is also the name in SQL.
+//! A different name, e.g. with a schema, is declared with `name = "sql_name"`:
+//!
+//! `sql_function!(trigram_similarity = "extensions.similarity"(String, String) -> f32);`
+
+use crate::column_value::UntypedColumnValue;
+use crate::prelude::{BoxedSql, ColumnType, Entity, EntityColumn, IntoSql, VirtualColumn};
+
+/// Argument of a function declared with [sql_function!](crate::sql_function!) or [sql_operator!](crate::sql_operator!).
+///
+/// Like [IntoSql], but columns also determine the entity of the result,
+/// so the functions can be nested, e.g. `concat(left(NAME, 2), "...")`.
+pub trait FunctionArgument<T, U: Entity> {
+    /// Convert self into a [BoxedSql]
+    fn argument_sql(&self) -> BoxedSql;
+}
+
+impl<T: ColumnType + UntypedColumnValue, U: Entity> FunctionArgument<T, U> for T {
+    fn argument_sql(&self) -> BoxedSql {
+        self.into_boxed_sql()
+    }
+}
+
+impl<T: ColumnType + UntypedColumnValue, U: Entity> FunctionArgument<T, U> for &T {
+    fn argument_sql(&self) -> BoxedSql {
+        IntoSql::<T>::into_boxed_sql(self)
+    }
+}
+
+impl<U: Entity> FunctionArgument<String, U> for &str {
+    fn argument_sql(&self) -> BoxedSql {
+        IntoSql::<String>::into_boxed_sql(self)
+    }
+}
+
+macro_rules! impl_function_argument {
+    ($column:ident, $column_type:ty) => {
+        impl<T: ColumnType, U: Entity> FunctionArgument<T, U> for $column<$column_type, U> {
+            fn argument_sql(&self) -> BoxedSql {
+                IntoSql::<T>::into_boxed_sql(self)
+            }
+        }
+    };
+}
+
+impl_function_argument!(EntityColumn, T);
+impl_function_argument!(EntityColumn, Option<T>);
+impl_function_argument!(VirtualColumn, T);
+impl_function_argument!(VirtualColumn, Option<T>);
+
+/// Declares a function of Postgres or of an extension, see [the module](mod@crate::sql_function).
+///
+/// ```
+/// use crash_orm::prelude::*;
+///
+/// sql_function!(
+///     /// Leftmost characters of a string
+///     pub left(String, i32) -> String
+/// );
+/// sql_function!(starts_with(String, String));
+/// ```
+///
+/// generates
+///
+/// ```ignore
+/// /// Leftmost characters of a string
+/// pub fn left<U: Entity>(arg0: impl FunctionArgument<String, U>, arg1: impl FunctionArgument<i32, U>) -> VirtualColumn<String, U>;
+/// fn starts_with<U: Entity>(arg0: impl FunctionArgument<String, U>, arg1: impl FunctionArgument<String, U>) -> QueryCondition<U>;
+/// ```
+#[macro_export]
+macro_rules! sql_function {
+    (@function [$($head:tt)*] [$($ret:tt)*] [$($params:tt)*] [$param:ident $($names:ident)*] $arg:ty, $($rest:ty,)*) => {
+        $crate::sql_function!(@function [$($head)*] [$($ret)*] [$($params)* $param: $arg,] [$($names)*] $($rest,)*);
+    };
+    (@function [$(#[$meta:meta])* $vis:vis $name:ident, $sql:expr] [-> $ret:ty] [$($param:ident: $arg:ty,)*] [$($names:ident)*]) => {
+        $(#[$meta])*
+        $vis fn $name<U: $crate::prelude::Entity>($($param: impl $crate::prelude::FunctionArgument<$arg, U>),*) -> $crate::prelude::VirtualColumn<$ret, U> {
+            $crate::prelude::VirtualColumn::new($crate::sql_function::function_sql($sql, vec![$($crate::prelude::FunctionArgument::<$arg, U>::argument_sql(&$param)),*]))
+        }
+    };
+    (@function [$(#[$meta:meta])* $vis:vis $name:ident, $sql:expr] [] [$($param:ident: $arg:ty,)*] [$($names:ident)*]) => {
+        $(#[$meta])*
+        $vis fn $name<U: $crate::prelude::Entity>($($param: impl $crate::prelude::FunctionArgument<$arg, U>),*) -> $crate::prelude::QueryCondition<U> {
+            $crate::prelude::QueryCondition::new($crate::sql_function::function_sql($sql, vec![$($crate::prelude::FunctionArgument::<$arg, U>::argument_sql(&$param)),*]))
+        }
+    };
+    (@function $head:tt $ret:tt $params:tt [] $($rest:ty,)+) => {
+        compile_error!("sql_function! supports at most 12 arguments");
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident = $sql:literal ($($arg:ty),* $(,)?) $(-> $ret:ty)?) => {
+        $crate::sql_function!(@function [$(#[$meta])* $vis $name, $sql] [$(-> $ret)?] []
+            [arg0 arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9 arg10 arg11] $($arg,)*);
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident ($($arg:ty),* $(,)?) $(-> $ret:ty)?) => {
+        $crate::sql_function!(@function [$(#[$meta])* $vis $name, stringify!($name)] [$(-> $ret)?] []
+            [arg0 arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9 arg10 arg11] $($arg,)*);
+    };
+}
+
+/// Declares a binary operator of Postgres or of an extension, see [the module](mod@crate::sql_function).
+///
+/// ```
+/// use crash_orm::prelude::*;
+///
+/// sql_operator!(
+///     /// Concatenation of two strings
+///     pub concat = "||"(String, String) -> String
+/// );
+/// sql_operator!(starts_with = "^@"(String, String));
+/// ```
+///
+/// generates
+///
+/// ```ignore
+/// /// Concatenation of two strings
+/// pub fn concat<U: Entity>(left: impl FunctionArgument<String, U>, right: impl FunctionArgument<String, U>) -> VirtualColumn<String, U>;
+/// fn starts_with<U: Entity>(left: impl FunctionArgument<String, U>, right: impl FunctionArgument<String, U>) -> QueryCondition<U>;
+/// ```
+#[macro_export]
+macro_rules! sql_operator {
+    ($(#[$meta:meta])* $vis:vis $name:ident = $operator:literal ($left:ty, $right:ty $(,)?) -> $ret:ty) => {
+        $(#[$meta])*
+        $vis fn $name<U: $crate::prelude::Entity>(left: impl $crate::prelude::FunctionArgument<$left, U>, right: impl $crate::prelude::FunctionArgument<$right, U>) -> $crate::prelude::VirtualColumn<$ret, U> {
+            $crate::prelude::VirtualColumn::new($crate::sql_function::operator_sql($operator, $crate::prelude::FunctionArgument::<$left, U>::argument_sql(&left), $crate::prelude::FunctionArgument::<$right, U>::argument_sql(&right)))
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident = $operator:literal ($left:ty, $right:ty $(,)?)) => {
+        $(#[$meta])*
+        $vis fn $name<U: $crate::prelude::Entity>(left: impl $crate::prelude::FunctionArgument<$left, U>, right: impl $crate::prelude::FunctionArgument<$right, U>) -> $crate::prelude::QueryCondition<U> {
+            $crate::prelude::QueryCondition::new($crate::sql_function::operator_sql($operator, $crate::prelude::FunctionArgument::<$left, U>::argument_sql(&left), $crate::prelude::FunctionArgument::<$right, U>::argument_sql(&right)))
+        }
+    };
+}
+
+/// Builds `name(arg0,arg1,...)`, used by [sql_function!](crate::sql_function!)
+#[doc(hidden)]
+pub fn function_sql(name: &str, args: Vec<BoxedSql>) -> BoxedSql {
+    let sql = args.iter().map(|arg| arg.sql.as_str()).collect::<Vec<_>>().join(",");
+    let values = args.into_iter().flat_map(|arg| arg.values).collect();
+    BoxedSql::new(format!("{}({})", name, sql), values)
+}
+
+/// Builds `(left operator right)`, used by [sql_operator!](crate::sql_operator!)
+#[doc(hidden)]
+pub fn operator_sql(operator: &str, left: BoxedSql, right: BoxedSql) -> BoxedSql {
+    let mut sql = left;
+    sql.modify(|v| format!("({} {} {})", v, operator, right.sql));
+    sql.values.extend(right.values);
+    sql
+}
@@ -0,0 +1,180 @@
+//! # Custom Types
+//! Any type implementing [ToSql](postgres::types::ToSql) and [FromSql](postgres::types::FromSql)
+//! can be used as property of an entity by implementing [SqlType] for it.
+//!
+//! [SqlType] declares the Postgres type of the column, which is used by the [Schema](crate::schema::Schema) derive
+//! for all types unknown to crash_orm.
+//! Columns of a [SqlType] support the [equality](crate::prelude::EqualQueryColumn) and [IN](crate::prelude::InQueryColumn) conditions,
+//! implementing [OrderedSqlType] adds the [comparison](crate::prelude::CompareQueryColumn) conditions.
+//!
+//! ```
+//! use std::error::Error;
+//! use crash_orm::prelude::*;
+//! use crash_orm::postgres::types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+//! use crash_orm::postgres::types::private::BytesMut;
+//! # use crash_orm_test::setup_test_connection;
+//!
+//! #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Email(String);
+//!
+//! impl SqlType for Email {
+//!     const SQL_TYPE: &'static str = "varchar(254)";
+//! }
+//!
+//! impl ToSql for Email {
+//!     fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+//!         self.0.to_sql(ty, out)
+//!     }
+//!
+//!     fn accepts(ty: &Type) -> bool {
+//!         <String as ToSql>::accepts(ty)
+//!     }
+//!
+//!     to_sql_checked!();
+//! }
+//!
+//! impl<'a> FromSql<'a> for Email {
+//!     fn from_sql(ty: &Type, raw: &'a [u8]) -> std::result::Result<Self, Box<dyn Error + Sync + Send>> {
+//!         String::from_sql(ty, raw).map(Email)
+//!     }
+//!
+//!     fn accepts(ty: &Type) -> bool {
+//!         <String as FromSql>::accepts(ty)
+//!     }
+//! }
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleSqlTypeEntity {
+//!     id: u32,
+//!     email: Email,
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! # let conn = setup_test_connection().await;
+//! ExampleSqlTypeEntity::create_table_if_not_exists(&conn).await.unwrap();
+//!
+//! let results = ExampleSqlTypeEntity::query()
+//!     .condition(ExampleSqlTypeEntityColumn::EMAIL.equals(Email(String::from("test@example.com"))))
+//!     .fetch(&conn).await.unwrap();
+//! # ExampleSqlTypeEntity::drop_table(&conn).await.unwrap();
+//! # });
+//! ```
+//!
+//! If a type needs to be created in the database first, like an [enum](crate::db_enum),
+//! override [SqlType::before_create_table].
+//!
+//! ## Attribute
+//! The Postgres type of a single property can also be set with `#[sql_type = "..."]`,
+//! e.g. to store a [String] as `citext` or `varchar(32)`:
+//!
+//! ```
+//! use crash_orm::prelude::*;
+//!
+//! #[derive(Entity, Debug, Schema)]
+//! struct ExampleSqlTypeAttribute {
+//!     id: u32,
+//!     #[sql_type = "varchar(32)"]
+//!     name: String,
+//! }
+//! ```
+//!
+//! Nullability is still derived from [Option].
+
+use async_trait::async_trait;
+
+use crate::prelude::{ColumnType, DatabaseConnection};
+
+/// Trait declaring the Postgres type of a custom column type, see [here](crate::sql_type).
+#[async_trait]
+pub trait SqlType: ColumnType + Clone {
+    /// Postgres type of the column, e.g. `citext`
+    const SQL_TYPE: &'static str;
+
+    /// Called by [Schema::create_table](crate::schema::Schema::create_table) before the table is created.
+    ///
+    /// The default implementation does nothing.
+    async fn before_create_table(_connection: &impl DatabaseConnection) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+/// Marks a [SqlType] as ordered in Postgres, which enables [CompareQueryColumn](crate::prelude::CompareQueryColumn) for its columns.
+pub trait OrderedSqlType: SqlType {}
+
+/// Postgres type of a column, used by the [Schema](crate::schema::Schema) derive.
+///
+/// Implemented for all [SqlType]s and the types of other crates supported by crash_orm,
+/// so the derive doesn't have to guess the type from its name.
+#[doc(hidden)]
+#[async_trait]
+pub trait SchemaColumnType {
+    /// Postgres type of the column
+    const COLUMN_TYPE: &'static str;
+
+    /// Called by [Schema::create_table](crate::schema::Schema::create_table) before the table is created.
+    async fn before_create_column(_connection: &impl DatabaseConnection) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: SqlType> SchemaColumnType for S {
+    const COLUMN_TYPE: &'static str = S::SQL_TYPE;
+
+    async fn before_create_column(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        S::before_create_table(connection).await
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! schema_column_type {
+    ($column_type:ty, $sql_type:literal) => {
+        impl SchemaColumnType for $column_type {
+            const COLUMN_TYPE: &'static str = $sql_type;
+        }
+    };
+}
+
+#[cfg(feature = "with-rust-decimal")]
+schema_column_type!(rust_decimal::Decimal, "numeric");
+#[cfg(feature = "with-uuid")]
+schema_column_type!(uuid::Uuid, "uuid");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::NaiveDateTime, "timestamp");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::DateTime<chrono::Utc>, "timestamp with time zone");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::DateTime<chrono::Local>, "timestamp with time zone");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::DateTime<chrono::FixedOffset>, "timestamp with time zone");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::NaiveDate, "date");
+#[cfg(feature = "with-chrono")]
+schema_column_type!(chrono::NaiveTime, "time");
+#[cfg(feature = "json")]
+schema_column_type!(serde_json::Value, "jsonb");
+#[cfg(feature = "with-eui48")]
+schema_column_type!(eui48::MacAddress, "macaddr");
+#[cfg(feature = "with-time")]
+schema_column_type!(time::PrimitiveDateTime, "timestamp");
+#[cfg(feature = "with-time")]
+schema_column_type!(time::OffsetDateTime, "timestamp with time zone");
+#[cfg(feature = "with-time")]
+schema_column_type!(time::Date, "date");
+#[cfg(feature = "with-time")]
+schema_column_type!(time::Time, "time");
+#[cfg(feature = "with-geo-types")]
+schema_column_type!(geo_types::Point, "point");
+#[cfg(feature = "with-geo-types")]
+schema_column_type!(geo_types::Rect, "box");
+#[cfg(feature = "with-geo-types")]
+schema_column_type!(geo_types::LineString, "path");
+
+#[cfg(feature = "json")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug + Clone + Send + Sync + 'static> SchemaColumnType for crate::json::TypedJson<T> {
+    const COLUMN_TYPE: &'static str = "jsonb";
+}
+
+impl<T: crate::range::RangeElement> SchemaColumnType for crate::range::Range<T> {
+    const COLUMN_TYPE: &'static str = T::RANGE_TYPE;
+}
@@ -0,0 +1,47 @@
+//! # Additional Types
+//! Types of Postgres, which are not supported by tokio-postgres or need a wrapper to be used as property of an entity.
+//!
+//! | Rust type                   | Postgres type | Feature            |
+//! |-----------------------------|---------------|--------------------|
+//! | [Interval]                  | INTERVAL      | `with-interval`    |
+//! | std::net::IpAddr            | INET          | `with-network`     |
+//! | [Cidr]                      | CIDR          | `with-network`     |
+//! | [Money]                     | MONEY         | `with-money`       |
+//! | [BitVarying]                | VARBIT        | `with-bit-varying` |
+//! | [Bytea] (bytes::Bytes)      | BYTEA         | `with-bytes`       |
+//! | [Citext]                    | CITEXT        | `with-citext`      |
+//!
+//! All of them implement [SqlType](crate::sql_type::SqlType), so they support the equality and IN conditions.
+//! [Interval], [Money] and [Citext] support the comparison conditions as well.
+//!
+//! ## Unsigned integers
+//! Postgres has no unsigned integer types.
+//! [u32] is mapped to OID, which is used for the default primary key.
+//! For all other unsigned integers use the next larger signed type, e.g. [i32] for [u16],
+//! optionally with a CHECK constraint in a migration.
+
+#[cfg(feature = "with-bit-varying")]
+pub use bit_varying::*;
+#[cfg(feature = "with-bytes")]
+pub use bytea::*;
+#[cfg(feature = "with-citext")]
+pub use citext::*;
+#[cfg(feature = "with-interval")]
+pub use interval::*;
+#[cfg(feature = "with-money")]
+pub use money::*;
+#[cfg(feature = "with-network")]
+pub use network::*;
+
+#[cfg(feature = "with-bit-varying")]
+mod bit_varying;
+#[cfg(feature = "with-bytes")]
+mod bytea;
+#[cfg(feature = "with-citext")]
+mod citext;
+#[cfg(feature = "with-interval")]
+mod interval;
+#[cfg(feature = "with-money")]
+mod money;
+#[cfg(feature = "with-network")]
+mod network;
@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::SqlType;
+
+/// Postgres bit varying, a string of bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitVarying {
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl BitVarying {
+    /// Creates an empty bit string
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true, if there are no bits
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at index, [None] if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// Appends a bit
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Returns an iterator over all bits
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|index| self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+}
+
+impl FromIterator<bool> for BitVarying {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut bits = Self::new();
+        for bit in iter {
+            bits.push(bit);
+        }
+
+        bits
+    }
+}
+
+impl SqlType for BitVarying {
+    const SQL_TYPE: &'static str = "varbit";
+}
+
+impl ToSql for BitVarying {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&i32::try_from(self.len)?.to_be_bytes());
+        out.extend_from_slice(&self.bytes);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::VARBIT || *ty == Type::BIT
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for BitVarying {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() < 4 {
+            return Err("invalid bit varying".into());
+        }
+
+        let len = usize::try_from(i32::from_be_bytes(raw[..4].try_into()?))?;
+        let bytes = raw[4..].to_vec();
+        if bytes.len() != len.div_ceil(8) {
+            return Err("invalid bit varying".into());
+        }
+
+        Ok(Self { len, bytes })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::VARBIT || *ty == Type::BIT
+    }
+}
@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::ops::Deref;
+
+use bytes::Bytes;
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::SqlType;
+
+/// Wrapper of [Bytes] stored as BYTEA.
+///
+/// tokio-postgres does not support [Bytes] directly. Alternatively, [`Vec<u8>`] is stored as BYTEA as well.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bytea(pub Bytes);
+
+impl Deref for Bytea {
+    type Target = Bytes;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Bytes> for Bytea {
+    fn from(value: Bytes) -> Self {
+        Self(value)
+    }
+}
+
+impl SqlType for Bytea {
+    const SQL_TYPE: &'static str = "bytea";
+}
+
+impl ToSql for Bytea {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        (&*self.0).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&[u8] as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Bytea {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Bytea(Bytes::copy_from_slice(<&[u8] as FromSql>::from_sql(ty, raw)?)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&[u8] as FromSql>::accepts(ty)
+    }
+}
@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::{DatabaseConnection, OrderedSqlType, SqlType};
+
+/// Case-insensitive text stored as CITEXT.
+///
+/// Creating a table with a column of this type creates the extension `citext` if it doesn't exist.
+/// Alternatively, a [String] can be stored as CITEXT with `#[sql_type = "citext"]`, see [here](crate::sql_type).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Citext(pub String);
+
+impl Deref for Citext {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<String> for Citext {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Citext {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[async_trait]
+impl SqlType for Citext {
+    const SQL_TYPE: &'static str = "citext";
+
+    async fn before_create_table(connection: &impl DatabaseConnection) -> crate::Result<()> {
+        connection.execute_query("CREATE EXTENSION IF NOT EXISTS citext", &[]).await?;
+
+        Ok(())
+    }
+}
+
+impl OrderedSqlType for Citext {}
+
+impl ToSql for Citext {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Citext {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        String::from_sql(ty, raw).map(Citext)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as FromSql>::accepts(ty)
+    }
+}
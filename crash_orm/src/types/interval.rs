@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::time::Duration;
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::{OrderedSqlType, SqlType};
+
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+/// Postgres interval, consisting of months, days and microseconds.
+///
+/// Months and days are kept separate, as their length depends on the date the interval is applied to.
+/// Can be created from a [Duration] or, with the feature `with-chrono`, from a [chrono::TimeDelta].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval {
+    /// Months of this interval
+    pub months: i32,
+    /// Days of this interval
+    pub days: i32,
+    /// Microseconds of this interval
+    pub microseconds: i64,
+}
+
+impl Interval {
+    /// Creates an interval
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Converts this interval into a [Duration], assuming 24 hours per day.
+    ///
+    /// Returns [None] if the interval contains months or is negative.
+    pub fn to_duration(&self) -> Option<Duration> {
+        if self.months != 0 {
+            return None;
+        }
+
+        let microseconds = (self.days as i64).checked_mul(MICROSECONDS_PER_DAY)?.checked_add(self.microseconds)?;
+        Some(Duration::from_micros(u64::try_from(microseconds).ok()?))
+    }
+}
+
+impl From<Duration> for Interval {
+    /// Converts the duration into microseconds, saturating at the maximum of the interval.
+    fn from(value: Duration) -> Self {
+        Self::new(0, 0, i64::try_from(value.as_micros()).unwrap_or(i64::MAX))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl From<chrono::TimeDelta> for Interval {
+    /// Converts the delta into microseconds, saturating at the bounds of the interval.
+    fn from(value: chrono::TimeDelta) -> Self {
+        let microseconds = value.num_microseconds().unwrap_or(if value < chrono::TimeDelta::zero() { i64::MIN } else { i64::MAX });
+        Self::new(0, 0, microseconds)
+    }
+}
+
+impl SqlType for Interval {
+    const SQL_TYPE: &'static str = "interval";
+}
+
+impl OrderedSqlType for Interval {}
+
+impl ToSql for Interval {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&self.microseconds.to_be_bytes());
+        out.extend_from_slice(&self.days.to_be_bytes());
+        out.extend_from_slice(&self.months.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid interval".into());
+        }
+
+        Ok(Self {
+            microseconds: i64::from_be_bytes(raw[0..8].try_into()?),
+            days: i32::from_be_bytes(raw[8..12].try_into()?),
+            months: i32::from_be_bytes(raw[12..16].try_into()?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::{OrderedSqlType, SqlType};
+
+/// Postgres money, stored as amount of the smallest unit of the currency, e.g. cents.
+///
+/// Note, that the number of fractional digits depends on the setting `lc_monetary` of the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Money(pub i64);
+
+impl SqlType for Money {
+    const SQL_TYPE: &'static str = "money";
+}
+
+impl OrderedSqlType for Money {}
+
+impl ToSql for Money {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&self.0.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::MONEY
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Money {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Money(i64::from_be_bytes(raw.try_into()?)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::MONEY
+    }
+}
@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use postgres::types::private::BytesMut;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::prelude::{Column, ColumnType, Entity, IntoSql, QueryCondition, SqlType};
+
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+impl SqlType for IpAddr {
+    const SQL_TYPE: &'static str = "inet";
+}
+
+/// Postgres cidr, an IPv4 or IPv6 network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cidr {
+    address: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Creates a network from its address and prefix length, e.g. `10.0.0.0/8`.
+    ///
+    /// Returns [None] if the prefix is too long for the address or the address has bits set right of the prefix.
+    pub fn new(address: IpAddr, prefix: u8) -> Option<Self> {
+        let (bytes, max_prefix) = match address {
+            IpAddr::V4(address) => (address.octets().to_vec(), 32),
+            IpAddr::V6(address) => (address.octets().to_vec(), 128),
+        };
+
+        if prefix > max_prefix {
+            return None;
+        }
+
+        let host_bits_set = bytes.iter().enumerate().any(|(index, byte)| {
+            let network_bits = (prefix as usize).saturating_sub(index * 8).min(8);
+            byte & (0xFFu8.checked_shr(network_bits as u32).unwrap_or(0)) != 0
+        });
+        if host_bits_set {
+            return None;
+        }
+
+        Some(Self { address, prefix })
+    }
+
+    /// Returns the network address
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Returns the prefix length
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+impl SqlType for Cidr {
+    const SQL_TYPE: &'static str = "cidr";
+}
+
+impl ToSql for Cidr {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        // The network may also be bound where postgres infers inet, e.g. `inet <<= $1`
+        let is_cidr = (*ty == Type::CIDR) as u8;
+        match self.address {
+            IpAddr::V4(address) => {
+                out.extend_from_slice(&[PGSQL_AF_INET, self.prefix, is_cidr, 4]);
+                out.extend_from_slice(&address.octets());
+            }
+            IpAddr::V6(address) => {
+                out.extend_from_slice(&[PGSQL_AF_INET6, self.prefix, is_cidr, 16]);
+                out.extend_from_slice(&address.octets());
+            }
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::CIDR || *ty == Type::INET
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Cidr {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let [family, prefix, _, length, address @ ..] = raw else {
+            return Err("invalid cidr".into());
+        };
+
+        let address = match (*family, *length, address.len()) {
+            (PGSQL_AF_INET, 4, 4) => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(address)?)),
+            (PGSQL_AF_INET6, 16, 16) => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(address)?)),
+            _ => return Err("invalid cidr".into()),
+        };
+
+        Ok(Self { address, prefix: *prefix })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::CIDR
+    }
+}
+
+/// Trait implementing network operator [QueryCondition]s for [IpAddr] and [Cidr] columns
+pub trait NetworkQueryColumn<T: ColumnType, U: Entity> {
+    /// Creates a condition checking if self is contained in or equals the network (`<<=`)
+    fn is_in_network(&self, network: impl IntoSql<Cidr>) -> QueryCondition<U>;
+}
+
+macro_rules! impl_network_query_column {
+    ($column_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> NetworkQueryColumn<$column_type, U> for R {
+            fn is_in_network(&self, network: impl IntoSql<Cidr>) -> QueryCondition<U> {
+                let mut boxed = self.get_sql();
+                let other_boxed = network.into_boxed_sql();
+                boxed.modify(|v| format!("{v} <<= {}", other_boxed.sql));
+                boxed.values.extend(other_boxed.values);
+
+                QueryCondition::new(boxed)
+            }
+        }
+    };
+}
+
+impl_network_query_column!(IpAddr);
+impl_network_query_column!(Cidr);
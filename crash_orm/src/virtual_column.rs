@@ -2,8 +2,16 @@
 
 use std::marker::PhantomData;
 
+pub use arithmetic_column::*;
+pub use array_agg_column::*;
+pub use array_column::*;
 pub use avg_column::*;
+pub use conditional_column::*;
 pub use count_column::*;
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+pub use date_time_column::*;
+#[cfg(feature = "json")]
+pub use json_column::*;
 pub use max_column::*;
 pub use min_column::*;
 pub use round_column::*;
@@ -11,8 +19,10 @@ pub use sqrt_column::*;
 pub use string_column::*;
 pub use sum_column::*;
 pub use text_cast_column::*;
+pub use ts_vector_column::*;
+pub use window_column::*;
 
-use crate::prelude::{BoxedSql, ColumnType, Entity};
+use crate::prelude::{BoxedSql, ColumnType, Entity, Query, SelectQueryType, SingleResult};
 
 mod string_column;
 mod round_column;
@@ -23,15 +33,27 @@ mod avg_column;
 mod sum_column;
 mod min_column;
 mod max_column;
+mod array_column;
+mod array_agg_column;
+mod ts_vector_column;
+mod window_column;
+mod arithmetic_column;
+mod conditional_column;
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+mod date_time_column;
+#[cfg(feature = "json")]
+mod json_column;
 
 /// Struct holding information about a non-existing column. This can be for example SQRT(number).
 ///
-/// These columns can be automatically generated by calling the corresponding functions, you cannot create them yourself!
+/// These columns are generated by calling the corresponding functions, e.g. [sqrt](SqrtVirtualColumn::sqrt).
 ///
-/// This also means, that you cannot add your own virtual columns.
+/// Functions and operators, which are not covered by this crate, can be declared with [sql_function!](crate::sql_function!)
+/// and [sql_operator!](crate::sql_operator!).
 /// If you need more virtual columns, please [open an issue at the repository](https://github.com/Cr4shd3v/crash_orm/issues/new/choose).
 pub struct VirtualColumn<T: ColumnType, U: Entity> {
     sql: BoxedSql,
+    alias: Option<String>,
     phantom_1: PhantomData<T>,
     phantom_2: PhantomData<U>,
 }
@@ -41,6 +63,7 @@ impl<T: ColumnType, U: Entity> VirtualColumn<T, U> {
     pub fn new(sql: BoxedSql) -> VirtualColumn<T, U> {
         VirtualColumn {
             sql,
+            alias: None,
             phantom_1: PhantomData,
             phantom_2: PhantomData,
         }
@@ -50,6 +73,42 @@ impl<T: ColumnType, U: Entity> VirtualColumn<T, U> {
         self.sql.clone()
     }
 
+    /// Names this column in the result of a select query.
+    ///
+    /// The alias is only applied in the selected columns, see [ResultMapping](crate::result_mapping).
+    pub fn alias(mut self, alias: &str) -> VirtualColumn<T, U> {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub(crate) fn get_select_sql(&self) -> BoxedSql {
+        let mut sql = self.get_sql();
+
+        if let Some(ref alias) = self.alias {
+            sql.modify(|sql| format!("{} AS \"{}\"", sql, alias.replace('"', "\"\"")));
+        }
+
+        sql
+    }
+
+    /// Scalar subquery, which returns the single value of the subquery or NULL if there is no row.
+    ///
+    /// The subquery must not return more than one row, e.g. use an aggregate like [CountColumn].
+    ///
+    /// The subquery can reference columns of the outer query with [outer](crate::prelude::EntityColumn::outer).
+    pub fn subquery<O: Entity>(subquery: Query<O, SingleResult<T>, SelectQueryType>) -> VirtualColumn<Option<T>, U> {
+        let mut sql = subquery.into_subquery_sql::<U>();
+        sql.modify(|v| format!("({v})"));
+        VirtualColumn::new(sql)
+    }
+
+    /// Calculates this aggregate over a [Window] instead of a group, e.g. for a running total:
+    ///
+    /// `AMOUNT.sum(false).over(&Window::new().order_by(&ID, OrderDirection::ASC))`
+    pub fn over(self, window: &Window<U>) -> VirtualColumn<T, U> {
+        window.apply(self.sql)
+    }
+
     /// Constant Column Pi
     pub fn pi() -> VirtualColumn<f64, U> {
         VirtualColumn::new(BoxedSql::new(String::from("PI()"), vec![]))
@@ -0,0 +1,117 @@
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, IntoSql, VirtualColumn};
+
+/// Trait implementing arithmetic operators to create [VirtualColumn]s for numeric columns.
+///
+/// The other value can be a value or another column, e.g. `PRICE.mul(AMOUNT)`.
+pub trait ArithmeticVirtualColumn<T: ColumnType, U: Entity> {
+    /// self + other
+    fn add(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// self - other
+    fn sub(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// self * other
+    fn mul(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// self / other
+    ///
+    /// Note, that the division of integers truncates the result.
+    fn div(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// Absolute value of self
+    fn abs(&self) -> VirtualColumn<T, U>;
+
+    /// -self
+    fn neg(&self) -> VirtualColumn<T, U>;
+}
+
+macro_rules! arithmetic_operator {
+    ($name:ident, $operator:literal, $column_type:ty) => {
+        fn $name(&self, other: impl IntoSql<$column_type>) -> VirtualColumn<$column_type, U> {
+            let mut sql = self.get_sql();
+            let other = other.into_boxed_sql();
+            sql.modify(|v| format!("({} {} {})", v, $operator, other.sql));
+            sql.values.extend(other.values);
+            VirtualColumn::new(sql)
+        }
+    };
+}
+
+macro_rules! impl_arithmetic_virtual_column {
+    ($column_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> ArithmeticVirtualColumn<$column_type, U> for R {
+            arithmetic_operator!(add, "+", $column_type);
+            arithmetic_operator!(sub, "-", $column_type);
+            arithmetic_operator!(mul, "*", $column_type);
+            arithmetic_operator!(div, "/", $column_type);
+
+            fn abs(&self) -> VirtualColumn<$column_type, U> {
+                let sql = self.get_sql();
+                VirtualColumn::new(BoxedSql::new(format!("ABS({})", sql.sql), sql.values))
+            }
+
+            fn neg(&self) -> VirtualColumn<$column_type, U> {
+                let sql = self.get_sql();
+                VirtualColumn::new(BoxedSql::new(format!("(-{})", sql.sql), sql.values))
+            }
+        }
+    };
+}
+
+impl_arithmetic_virtual_column!(i16);
+impl_arithmetic_virtual_column!(i32);
+impl_arithmetic_virtual_column!(i64);
+impl_arithmetic_virtual_column!(f32);
+impl_arithmetic_virtual_column!(f64);
+#[cfg(feature = "with-rust-decimal")]
+impl_arithmetic_virtual_column!(rust_decimal::Decimal);
+
+/// Trait implementing the modulo operator to create [VirtualColumn]s for integer and decimal columns
+pub trait ModuloVirtualColumn<T: ColumnType, U: Entity> {
+    /// Remainder of self / other
+    fn modulo(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+}
+
+macro_rules! impl_modulo_virtual_column {
+    ($column_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> ModuloVirtualColumn<$column_type, U> for R {
+            arithmetic_operator!(modulo, "%", $column_type);
+        }
+    };
+}
+
+impl_modulo_virtual_column!(i16);
+impl_modulo_virtual_column!(i32);
+impl_modulo_virtual_column!(i64);
+#[cfg(feature = "with-rust-decimal")]
+impl_modulo_virtual_column!(rust_decimal::Decimal);
+
+/// Trait implementing the power function to create [VirtualColumn]s for numeric columns.
+///
+/// The result is a f64, or a decimal for decimal columns.
+pub trait PowerVirtualColumn<T: ColumnType, R: ColumnType, U: Entity> {
+    /// self raised to the power of exponent
+    fn power(&self, exponent: impl IntoSql<R>) -> VirtualColumn<R, U>;
+}
+
+macro_rules! impl_power_virtual_column {
+    ($column_type:ty, $out_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> PowerVirtualColumn<$column_type, $out_type, U> for R {
+            fn power(&self, exponent: impl IntoSql<$out_type>) -> VirtualColumn<$out_type, U> {
+                let mut sql = self.get_sql();
+                let exponent = exponent.into_boxed_sql();
+                sql.modify(|v| format!("POWER({},{})", v, exponent.sql));
+                sql.values.extend(exponent.values);
+                VirtualColumn::new(sql)
+            }
+        }
+    };
+}
+
+impl_power_virtual_column!(i16, f64);
+impl_power_virtual_column!(i32, f64);
+impl_power_virtual_column!(i64, f64);
+impl_power_virtual_column!(f32, f64);
+impl_power_virtual_column!(f64, f64);
+#[cfg(feature = "with-rust-decimal")]
+impl_power_virtual_column!(rust_decimal::Decimal, rust_decimal::Decimal);
@@ -0,0 +1,19 @@
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, VirtualColumn};
+
+/// Trait implementing the array_agg function for a column.
+///
+/// Note, that aggregated values like this cannot be used in where statements.
+pub trait ArrayAggColumn<T: ColumnType, U: Entity> {
+    /// Collects all values into an array
+    fn array_agg(&self, distinct: bool) -> VirtualColumn<Vec<T>, U>;
+}
+
+impl<T: ColumnType, U: Entity, C: Column<T, U>> ArrayAggColumn<T, U> for C {
+    fn array_agg(&self, distinct: bool) -> VirtualColumn<Vec<T>, U> {
+        let sql = self.get_sql();
+        VirtualColumn::new(BoxedSql::new(
+            format!("ARRAY_AGG({}{})", if distinct { "DISTINCT " } else { "" }, sql.sql),
+            sql.values,
+        ))
+    }
+}
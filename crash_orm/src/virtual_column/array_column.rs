@@ -0,0 +1,30 @@
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, VirtualColumn};
+
+/// Trait implementing array database functions to create [VirtualColumn]s for array columns
+pub trait ArrayVirtualColumn<T: ColumnType, U: Entity> {
+    /// Get the number of elements of self
+    fn array_length(&self) -> VirtualColumn<i32, U>;
+
+    /// Expand self to a set of rows, one row per element
+    ///
+    /// Note, that this is a set returning function, which can only be used in the selected columns.
+    fn unnest(&self) -> VirtualColumn<T, U>;
+}
+
+impl<T: ColumnType + Clone, U: Entity, R: Column<Vec<T>, U>> ArrayVirtualColumn<T, U> for R {
+    fn array_length(&self) -> VirtualColumn<i32, U> {
+        let sql = self.get_sql();
+        VirtualColumn::new(BoxedSql::new(
+            format!("CARDINALITY({})", sql.sql),
+            sql.values,
+        ))
+    }
+
+    fn unnest(&self) -> VirtualColumn<T, U> {
+        let sql = self.get_sql();
+        VirtualColumn::new(BoxedSql::new(
+            format!("UNNEST({})", sql.sql),
+            sql.values,
+        ))
+    }
+}
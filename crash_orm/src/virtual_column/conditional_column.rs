@@ -0,0 +1,151 @@
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, IntoSql, QueryCondition, SqlType, VirtualColumn};
+
+/// Trait implementing null-handling and comparison functions to create [VirtualColumn]s for all columns
+pub trait ConditionalVirtualColumn<T: ColumnType, U: Entity> {
+    /// First value of self and other which is not NULL
+    fn coalesce(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// NULL if self equals other, otherwise self
+    fn nullif(&self, other: impl IntoSql<T>) -> VirtualColumn<Option<T>, U>;
+
+    /// Larger value of self and other, NULL values are ignored
+    fn greatest(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+
+    /// Smaller value of self and other, NULL values are ignored
+    fn least(&self, other: impl IntoSql<T>) -> VirtualColumn<T, U>;
+}
+
+/// Column types with a name in Postgres, required to cast the values of a [Case].
+pub trait CaseType: ColumnType {
+    /// Name of the type in Postgres, e.g. `int4`
+    const CAST_TYPE: &'static str;
+}
+
+impl<S: SqlType> CaseType for S {
+    const CAST_TYPE: &'static str = S::SQL_TYPE;
+}
+
+macro_rules! conditional_function {
+    ($name:ident, $function:literal, $column_type:ty, $out_type:ty) => {
+        fn $name(&self, other: impl IntoSql<$column_type>) -> VirtualColumn<$out_type, U> {
+            let mut sql = self.get_sql();
+            let other = other.into_boxed_sql();
+            sql.modify(|v| format!("{}({},{})", $function, v, other.sql));
+            sql.values.extend(other.values);
+            VirtualColumn::new(sql)
+        }
+    };
+}
+
+macro_rules! impl_conditional_virtual_column {
+    ([$($generics:tt)*] $column_type:ty) => {
+        impl<$($generics)* U: Entity, R: Column<$column_type, U>> ConditionalVirtualColumn<$column_type, U> for R {
+            conditional_function!(coalesce, "COALESCE", $column_type, $column_type);
+            conditional_function!(nullif, "NULLIF", $column_type, Option<$column_type>);
+            conditional_function!(greatest, "GREATEST", $column_type, $column_type);
+            conditional_function!(least, "LEAST", $column_type, $column_type);
+        }
+    };
+    ($column_type:ty, $cast_type:literal) => {
+        impl CaseType for $column_type {
+            const CAST_TYPE: &'static str = $cast_type;
+        }
+
+        impl_conditional_virtual_column!([] $column_type);
+    };
+}
+
+impl_conditional_virtual_column!([S: SqlType,] S);
+impl_conditional_virtual_column!(bool, "bool");
+impl_conditional_virtual_column!(i16, "int2");
+impl_conditional_virtual_column!(i32, "int4");
+impl_conditional_virtual_column!(i64, "int8");
+impl_conditional_virtual_column!(f32, "float4");
+impl_conditional_virtual_column!(f64, "float8");
+#[cfg(feature = "with-rust-decimal")]
+impl_conditional_virtual_column!(rust_decimal::Decimal, "numeric");
+impl_conditional_virtual_column!(String, "text");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::NaiveDateTime, "timestamp");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::DateTime<chrono::Utc>, "timestamptz");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::DateTime<chrono::Local>, "timestamptz");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::DateTime<chrono::FixedOffset>, "timestamptz");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::NaiveDate, "date");
+#[cfg(feature = "with-chrono")]
+impl_conditional_virtual_column!(chrono::NaiveTime, "time");
+#[cfg(feature = "with-uuid")]
+impl_conditional_virtual_column!(uuid::Uuid, "uuid");
+#[cfg(feature = "with-time")]
+impl_conditional_virtual_column!(time::PrimitiveDateTime, "timestamp");
+#[cfg(feature = "with-time")]
+impl_conditional_virtual_column!(time::OffsetDateTime, "timestamptz");
+#[cfg(feature = "with-time")]
+impl_conditional_virtual_column!(time::Date, "date");
+#[cfg(feature = "with-time")]
+impl_conditional_virtual_column!(time::Time, "time");
+
+/// Builder for `CASE WHEN condition THEN value ... ELSE value END`.
+///
+/// The branches are checked in order, the value of the first matching condition is the result:
+///
+/// `Case::new().when(SCORE.greater_equal(90), "A").when(SCORE.greater_equal(50), "B").otherwise("C")`
+pub struct Case<T: CaseType, U: Entity> {
+    branches: Vec<(QueryCondition<U>, BoxedSql)>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: CaseType, U: Entity> Default for Case<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CaseType, U: Entity> Case<T, U> {
+    /// Creates a CASE without branches, at least one branch must be added with [when](Self::when)
+    pub fn new() -> Case<T, U> {
+        Self {
+            branches: vec![],
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a branch, the value is the result if the condition matches and no previous branch matched
+    pub fn when(mut self, condition: QueryCondition<U>, then: impl IntoSql<T>) -> Case<T, U> {
+        self.branches.push((condition, then.into_boxed_sql()));
+        self
+    }
+
+    /// Finishes the CASE with the value as result, if no branch matches
+    pub fn otherwise(self, value: impl IntoSql<T>) -> VirtualColumn<T, U> {
+        VirtualColumn::new(self.build(Some(value.into_boxed_sql())))
+    }
+
+    /// Finishes the CASE without a default, the result is NULL if no branch matches
+    pub fn end(self) -> VirtualColumn<Option<T>, U> {
+        VirtualColumn::new(self.build(None))
+    }
+
+    fn build(self, otherwise: Option<BoxedSql>) -> BoxedSql {
+        let mut sql = String::from("CASE");
+        let mut values = vec![];
+
+        // Values are cast, otherwise Postgres assumes text for parameters
+        for (condition, then) in self.branches {
+            sql.push_str(&format!(" WHEN {} THEN CAST({} AS {})", condition.boxed.sql, then.sql, T::CAST_TYPE));
+            values.extend(condition.boxed.values);
+            values.extend(then.values);
+        }
+
+        if let Some(otherwise) = otherwise {
+            sql.push_str(&format!(" ELSE CAST({} AS {})", otherwise.sql, T::CAST_TYPE));
+            values.extend(otherwise.values);
+        }
+
+        sql.push_str(" END");
+        BoxedSql::new(sql, values)
+    }
+}
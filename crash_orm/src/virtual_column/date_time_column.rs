@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+use crate::prelude::{BoxedSql, CaseType, Column, Entity, IntoSql, VirtualColumn};
+#[cfg(feature = "with-interval")]
+use crate::prelude::Interval;
+
+/// Date and timestamp types of chrono and time
+pub trait DateTimeType: CaseType {}
+
+/// Field of a date or timestamp for [date_trunc](DateTimeVirtualColumn::date_trunc) and [extract](DateTimeVirtualColumn::extract)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeField {
+    #[allow(missing_docs)]
+    Microseconds,
+    #[allow(missing_docs)]
+    Milliseconds,
+    #[allow(missing_docs)]
+    Second,
+    #[allow(missing_docs)]
+    Minute,
+    #[allow(missing_docs)]
+    Hour,
+    #[allow(missing_docs)]
+    Day,
+    /// ISO week, starting on Monday
+    Week,
+    #[allow(missing_docs)]
+    Month,
+    #[allow(missing_docs)]
+    Quarter,
+    #[allow(missing_docs)]
+    Year,
+    #[allow(missing_docs)]
+    Decade,
+    #[allow(missing_docs)]
+    Century,
+    #[allow(missing_docs)]
+    Millennium,
+    /// Day of the week from 0 (Sunday) to 6 (Saturday), only for extract
+    DayOfWeek,
+    /// Day of the week from 1 (Monday) to 7 (Sunday), only for extract
+    IsoDayOfWeek,
+    /// Day of the year from 1 to 366, only for extract
+    DayOfYear,
+    /// Seconds since 1970-01-01 00:00:00 UTC, only for extract
+    Epoch,
+}
+
+impl Display for DateTimeField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            DateTimeField::Microseconds => "microseconds",
+            DateTimeField::Milliseconds => "milliseconds",
+            DateTimeField::Second => "second",
+            DateTimeField::Minute => "minute",
+            DateTimeField::Hour => "hour",
+            DateTimeField::Day => "day",
+            DateTimeField::Week => "week",
+            DateTimeField::Month => "month",
+            DateTimeField::Quarter => "quarter",
+            DateTimeField::Year => "year",
+            DateTimeField::Decade => "decade",
+            DateTimeField::Century => "century",
+            DateTimeField::Millennium => "millennium",
+            DateTimeField::DayOfWeek => "dow",
+            DateTimeField::IsoDayOfWeek => "isodow",
+            DateTimeField::DayOfYear => "doy",
+            DateTimeField::Epoch => "epoch",
+        })
+    }
+}
+
+impl<T: DateTimeType, U: Entity> VirtualColumn<T, U> {
+    /// Current date and time, `NOW()`, e.g. `VirtualColumn::<DateTime<Utc>, Event>::now()`.
+    ///
+    /// Timestamps without time zone and dates are converted with the time zone of the session.
+    pub fn now() -> VirtualColumn<T, U> {
+        VirtualColumn::new(BoxedSql::new(format!("CAST(NOW() AS {})", T::CAST_TYPE), vec![]))
+    }
+
+    /// Current date, `CURRENT_DATE`, for timestamps at midnight
+    pub fn current_date() -> VirtualColumn<T, U> {
+        VirtualColumn::new(BoxedSql::new(format!("CAST(CURRENT_DATE AS {})", T::CAST_TYPE), vec![]))
+    }
+}
+
+/// Trait implementing date and time functions to create [VirtualColumn]s for date and timestamp columns
+pub trait DateTimeVirtualColumn<T: DateTimeType, U: Entity> {
+    /// Truncates self to the precision of the field, e.g. the first day of the week with [DateTimeField::Week].
+    ///
+    /// The field is not bound as parameter, so the column can be selected and grouped by at the same time.
+    fn date_trunc(&self, field: DateTimeField) -> VirtualColumn<T, U>;
+
+    /// Extracts the field of self, e.g. the hour with [DateTimeField::Hour] (`date_part`)
+    fn extract(&self, field: DateTimeField) -> VirtualColumn<f64, U>;
+}
+
+/// Trait implementing `AT TIME ZONE` to create [VirtualColumn]s for timestamp columns.
+///
+/// A timestamp with time zone is converted into the local time of the zone,
+/// a timestamp without time zone is interpreted as local time of the zone.
+pub trait TimeZoneVirtualColumn<T: DateTimeType, R: DateTimeType, U: Entity> {
+    /// Converts self to the time zone, e.g. `Europe/Berlin`
+    fn at_time_zone(&self, zone: impl IntoSql<String>) -> VirtualColumn<R, U>;
+}
+
+/// Trait implementing interval arithmetic to create [VirtualColumn]s for date and timestamp columns
+#[cfg(feature = "with-interval")]
+pub trait IntervalVirtualColumn<T: DateTimeType, U: Entity> {
+    /// self + interval, the time of a date is dropped
+    fn add_interval(&self, interval: impl IntoSql<Interval>) -> VirtualColumn<T, U>;
+
+    /// self - interval, the time of a date is dropped
+    fn sub_interval(&self, interval: impl IntoSql<Interval>) -> VirtualColumn<T, U>;
+
+    /// Interval between self and other in years, months and days, e.g. `1 year 2 mons 3 days` (`age`)
+    fn age(&self, other: impl IntoSql<T>) -> VirtualColumn<Interval, U>;
+}
+
+macro_rules! impl_date_time_virtual_column {
+    ($column_type:ty) => {
+        impl DateTimeType for $column_type {}
+
+        impl<U: Entity, R: Column<$column_type, U>> DateTimeVirtualColumn<$column_type, U> for R {
+            fn date_trunc(&self, field: DateTimeField) -> VirtualColumn<$column_type, U> {
+                let mut sql = self.get_sql();
+                sql.modify(|v| format!("CAST(DATE_TRUNC('{}', {}) AS {})", field, v, <$column_type as CaseType>::CAST_TYPE));
+                VirtualColumn::new(sql)
+            }
+
+            fn extract(&self, field: DateTimeField) -> VirtualColumn<f64, U> {
+                let mut sql = self.get_sql();
+                sql.modify(|v| format!("DATE_PART('{}', {})", field, v));
+                VirtualColumn::new(sql)
+            }
+        }
+
+        #[cfg(feature = "with-interval")]
+        impl<U: Entity, R: Column<$column_type, U>> IntervalVirtualColumn<$column_type, U> for R {
+            fn add_interval(&self, interval: impl IntoSql<Interval>) -> VirtualColumn<$column_type, U> {
+                let mut sql = self.get_sql();
+                let interval = interval.into_boxed_sql();
+                sql.modify(|v| format!("CAST({} + CAST({} AS interval) AS {})", v, interval.sql, <$column_type as CaseType>::CAST_TYPE));
+                sql.values.extend(interval.values);
+                VirtualColumn::new(sql)
+            }
+
+            fn sub_interval(&self, interval: impl IntoSql<Interval>) -> VirtualColumn<$column_type, U> {
+                let mut sql = self.get_sql();
+                let interval = interval.into_boxed_sql();
+                sql.modify(|v| format!("CAST({} - CAST({} AS interval) AS {})", v, interval.sql, <$column_type as CaseType>::CAST_TYPE));
+                sql.values.extend(interval.values);
+                VirtualColumn::new(sql)
+            }
+
+            fn age(&self, other: impl IntoSql<$column_type>) -> VirtualColumn<Interval, U> {
+                let mut sql = self.get_sql();
+                let other = other.into_boxed_sql();
+                sql.modify(|v| format!("AGE({},CAST({} AS {}))", v, other.sql, <$column_type as CaseType>::CAST_TYPE));
+                sql.values.extend(other.values);
+                VirtualColumn::new(sql)
+            }
+        }
+    };
+}
+
+macro_rules! impl_time_zone_virtual_column {
+    ($column_type:ty, $out_type:ty) => {
+        impl<U: Entity, R: Column<$column_type, U>> TimeZoneVirtualColumn<$column_type, $out_type, U> for R {
+            fn at_time_zone(&self, zone: impl IntoSql<String>) -> VirtualColumn<$out_type, U> {
+                let mut sql = self.get_sql();
+                let zone = zone.into_boxed_sql();
+                sql.modify(|v| format!("({} AT TIME ZONE {})", v, zone.sql));
+                sql.values.extend(zone.values);
+                VirtualColumn::new(sql)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "with-chrono")]
+impl_date_time_virtual_column!(chrono::NaiveDateTime);
+#[cfg(feature = "with-chrono")]
+impl_date_time_virtual_column!(chrono::DateTime<chrono::Utc>);
+#[cfg(feature = "with-chrono")]
+impl_date_time_virtual_column!(chrono::DateTime<chrono::Local>);
+#[cfg(feature = "with-chrono")]
+impl_date_time_virtual_column!(chrono::DateTime<chrono::FixedOffset>);
+#[cfg(feature = "with-chrono")]
+impl_date_time_virtual_column!(chrono::NaiveDate);
+#[cfg(feature = "with-time")]
+impl_date_time_virtual_column!(time::PrimitiveDateTime);
+#[cfg(feature = "with-time")]
+impl_date_time_virtual_column!(time::OffsetDateTime);
+#[cfg(feature = "with-time")]
+impl_date_time_virtual_column!(time::Date);
+
+#[cfg(feature = "with-chrono")]
+impl_time_zone_virtual_column!(chrono::NaiveDateTime, chrono::DateTime<chrono::Utc>);
+#[cfg(feature = "with-chrono")]
+impl_time_zone_virtual_column!(chrono::DateTime<chrono::Utc>, chrono::NaiveDateTime);
+#[cfg(feature = "with-chrono")]
+impl_time_zone_virtual_column!(chrono::DateTime<chrono::Local>, chrono::NaiveDateTime);
+#[cfg(feature = "with-chrono")]
+impl_time_zone_virtual_column!(chrono::DateTime<chrono::FixedOffset>, chrono::NaiveDateTime);
+#[cfg(feature = "with-time")]
+impl_time_zone_virtual_column!(time::PrimitiveDateTime, time::OffsetDateTime);
+#[cfg(feature = "with-time")]
+impl_time_zone_virtual_column!(time::OffsetDateTime, time::PrimitiveDateTime);
@@ -0,0 +1,50 @@
+use serde_json::Value;
+
+use crate::prelude::{BoxedSql, Column, Entity, IntoSql, JsonColumnType, VirtualColumn};
+
+/// Trait implementing JSONB path extraction to create [VirtualColumn]s for json columns
+pub trait JsonVirtualColumn<T: JsonColumnType, U: Entity> {
+    /// Get the field key of self as json (`->`)
+    fn json_get(&self, key: impl IntoSql<String>) -> VirtualColumn<Value, U>;
+
+    /// Get the field key of self as text (`->>`)
+    fn json_get_text(&self, key: impl IntoSql<String>) -> VirtualColumn<String, U>;
+
+    /// Get the value at path of self as text (`#>>`), e.g. `vec!["address", "city"]`
+    fn json_get_path_text(&self, path: impl IntoSql<Vec<String>>) -> VirtualColumn<String, U>;
+}
+
+impl<T: JsonColumnType, U: Entity, R: Column<T, U>> JsonVirtualColumn<T, U> for R {
+    fn json_get(&self, key: impl IntoSql<String>) -> VirtualColumn<Value, U> {
+        let sql = self.get_sql();
+        let key_sql = key.into_boxed_sql();
+        let mut values = sql.values;
+        values.extend(key_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("({} -> {}::text)", sql.sql, key_sql.sql),
+            values,
+        ))
+    }
+
+    fn json_get_text(&self, key: impl IntoSql<String>) -> VirtualColumn<String, U> {
+        let sql = self.get_sql();
+        let key_sql = key.into_boxed_sql();
+        let mut values = sql.values;
+        values.extend(key_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("({} ->> {}::text)", sql.sql, key_sql.sql),
+            values,
+        ))
+    }
+
+    fn json_get_path_text(&self, path: impl IntoSql<Vec<String>>) -> VirtualColumn<String, U> {
+        let sql = self.get_sql();
+        let path_sql = path.into_boxed_sql();
+        let mut values = sql.values;
+        values.extend(path_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("({} #>> {}::text[])", sql.sql, path_sql.sql),
+            values,
+        ))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::{BoxedSql, Column, Entity, IntoSql, UntypedColumnValue, VirtualColumn};
+use crate::prelude::{BoxedSql, Column, Entity, IntoSql, TsQuery, TsVector, UntypedColumnValue, VirtualColumn};
 
 /// Trait implementing string database functions to create [VirtualColumn]s for string columns
 pub trait StringVirtualColumn<U: Entity> {
@@ -22,6 +22,17 @@ pub trait StringVirtualColumn<U: Entity> {
 
     /// Creates the md5 hash of this string
     fn md5(&self) -> VirtualColumn<String, U>;
+
+    /// Convert self to a [TsVector] using the text search configuration, e.g. `english`
+    fn to_tsvector(&self, config: impl IntoSql<String>) -> VirtualColumn<TsVector, U>;
+
+    /// Creates a snippet of self with the matches of the query highlighted
+    fn ts_headline(&self, query: &TsQuery) -> VirtualColumn<String, U>;
+
+    /// Creates a snippet of self with the matches of the query highlighted.
+    ///
+    /// The options are passed to Postgres, e.g. `StartSel=<b>, StopSel=</b>, MaxWords=20`
+    fn ts_headline_with_options(&self, query: &TsQuery, options: impl IntoSql<String>) -> VirtualColumn<String, U>;
 }
 
 impl<U: Entity, R: Column<String, U>> StringVirtualColumn<U> for R {
@@ -87,4 +98,43 @@ impl<U: Entity, R: Column<String, U>> StringVirtualColumn<U> for R {
             sql.values,
         ))
     }
+
+    fn to_tsvector(&self, config: impl IntoSql<String>) -> VirtualColumn<TsVector, U> {
+        let sql = self.get_sql();
+        let config_sql = config.into_boxed_sql();
+        let mut values = config_sql.values;
+        values.extend(sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("to_tsvector(CAST({}::text AS regconfig),{})", config_sql.sql, sql.sql),
+            values,
+        ))
+    }
+
+    fn ts_headline(&self, query: &TsQuery) -> VirtualColumn<String, U> {
+        let sql = self.get_sql();
+        let config_sql = query.get_config_sql();
+        let query_sql = query.get_sql();
+        let mut values = config_sql.values;
+        values.extend(sql.values);
+        values.extend(query_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("ts_headline({},{},{})", config_sql.sql, sql.sql, query_sql.sql),
+            values,
+        ))
+    }
+
+    fn ts_headline_with_options(&self, query: &TsQuery, options: impl IntoSql<String>) -> VirtualColumn<String, U> {
+        let sql = self.get_sql();
+        let config_sql = query.get_config_sql();
+        let query_sql = query.get_sql();
+        let options_sql = options.into_boxed_sql();
+        let mut values = config_sql.values;
+        values.extend(sql.values);
+        values.extend(query_sql.values);
+        values.extend(options_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("ts_headline({},{},{},{})", config_sql.sql, sql.sql, query_sql.sql, options_sql.sql),
+            values,
+        ))
+    }
 }
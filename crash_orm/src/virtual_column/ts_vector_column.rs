@@ -0,0 +1,32 @@
+use crate::prelude::{BoxedSql, Column, Entity, TsQuery, TsVector, VirtualColumn};
+
+/// Trait implementing ranking functions to create [VirtualColumn]s for [TsVector] columns
+pub trait TsVectorVirtualColumn<U: Entity> {
+    /// Ranks self against the query by the frequency of the matching lexemes (`ts_rank`)
+    fn ts_rank(&self, query: &TsQuery) -> VirtualColumn<f32, U>;
+
+    /// Ranks self against the query by the proximity of the matching lexemes (`ts_rank_cd`)
+    fn ts_rank_cd(&self, query: &TsQuery) -> VirtualColumn<f32, U>;
+}
+
+impl<U: Entity, R: Column<TsVector, U>> TsVectorVirtualColumn<U> for R {
+    fn ts_rank(&self, query: &TsQuery) -> VirtualColumn<f32, U> {
+        let mut sql = self.get_sql();
+        let query_sql = query.get_sql();
+        sql.values.extend(query_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("ts_rank({},{})", sql.sql, query_sql.sql),
+            sql.values,
+        ))
+    }
+
+    fn ts_rank_cd(&self, query: &TsQuery) -> VirtualColumn<f32, U> {
+        let mut sql = self.get_sql();
+        let query_sql = query.get_sql();
+        sql.values.extend(query_sql.values);
+        VirtualColumn::new(BoxedSql::new(
+            format!("ts_rank_cd({},{})", sql.sql, query_sql.sql),
+            sql.values,
+        ))
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::Display;
+
+use crate::prelude::{BoxedSql, Column, ColumnType, Entity, IntoSql, OrderDirection, UntypedColumn, VirtualColumn};
+
+/// Window of a window function, translated to `OVER (PARTITION BY ... ORDER BY ... ROWS ...)`.
+///
+/// Window functions are created with the ranking functions of the window, e.g. [row_number](Self::row_number),
+/// with [WindowVirtualColumn] or from aggregates with [over](VirtualColumn::over).
+///
+/// Note, that window functions cannot be used in where statements.
+pub struct Window<U: Entity> {
+    partition_by: Vec<BoxedSql>,
+    order_by: Vec<(BoxedSql, OrderDirection)>,
+    frame: Option<String>,
+    phantom: std::marker::PhantomData<U>,
+}
+
+/// Bound of the frame of a [Window]
+#[derive(Debug, Clone, Copy)]
+pub enum FrameBound {
+    /// UNBOUNDED PRECEDING, the first row of the partition
+    UnboundedPreceding,
+    /// n PRECEDING
+    Preceding(u32),
+    /// CURRENT ROW
+    CurrentRow,
+    /// n FOLLOWING
+    Following(u32),
+    /// UNBOUNDED FOLLOWING, the last row of the partition
+    UnboundedFollowing,
+}
+
+impl Display for FrameBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameBound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(offset) => write!(f, "{} PRECEDING", offset),
+            FrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            FrameBound::Following(offset) => write!(f, "{} FOLLOWING", offset),
+            FrameBound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl<U: Entity> Default for Window<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: Entity> Window<U> {
+    /// Creates a window over all rows
+    pub fn new() -> Window<U> {
+        Self {
+            partition_by: vec![],
+            order_by: vec![],
+            frame: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a partition to this window, the window function is calculated for each partition separately.
+    pub fn partition_by(mut self, column: &dyn UntypedColumn<U>) -> Window<U> {
+        self.partition_by.push(column.get_sql());
+        self
+    }
+
+    /// Add an order to this window
+    pub fn order_by(mut self, column: &dyn UntypedColumn<U>, direction: OrderDirection) -> Window<U> {
+        self.order_by.push((column.get_sql(), direction));
+        self
+    }
+
+    /// Set the frame of this window in rows, e.g. `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` for a running total.
+    pub fn rows(mut self, start: FrameBound, end: FrameBound) -> Window<U> {
+        self.frame = Some(format!("ROWS BETWEEN {} AND {}", start, end));
+        self
+    }
+
+    /// Set the frame of this window in values of the order, rows with the same value are in the same frame.
+    pub fn range(mut self, start: FrameBound, end: FrameBound) -> Window<U> {
+        self.frame = Some(format!("RANGE BETWEEN {} AND {}", start, end));
+        self
+    }
+
+    /// Number of the current row in the partition, starting at 1
+    pub fn row_number(&self) -> VirtualColumn<i64, U> {
+        self.apply(BoxedSql::new(String::from("ROW_NUMBER()"), vec![]))
+    }
+
+    /// Rank of the current row in the partition with gaps, e.g. 1, 1, 3
+    pub fn rank(&self) -> VirtualColumn<i64, U> {
+        self.apply(BoxedSql::new(String::from("RANK()"), vec![]))
+    }
+
+    /// Rank of the current row in the partition without gaps, e.g. 1, 1, 2
+    pub fn dense_rank(&self) -> VirtualColumn<i64, U> {
+        self.apply(BoxedSql::new(String::from("DENSE_RANK()"), vec![]))
+    }
+
+    /// Applies this window to the sql of a function
+    pub(crate) fn apply<T: ColumnType>(&self, mut function: BoxedSql) -> VirtualColumn<T, U> {
+        let mut parts = vec![];
+
+        if !self.partition_by.is_empty() {
+            parts.push(format!("PARTITION BY {}", self.partition_by.iter().map(|v| &*v.sql).collect::<Vec<&str>>().join(",")));
+            for partition in &self.partition_by {
+                function.values.extend(partition.values.clone());
+            }
+        }
+
+        if !self.order_by.is_empty() {
+            parts.push(format!(
+                "ORDER BY {}",
+                self.order_by.iter().map(|(column, direction)| format!("{} {}", column.sql, direction)).collect::<Vec<String>>().join(","),
+            ));
+            for (column, _) in &self.order_by {
+                function.values.extend(column.values.clone());
+            }
+        }
+
+        if let Some(ref frame) = self.frame {
+            parts.push(frame.clone());
+        }
+
+        function.modify(|v| format!("{} OVER ({})", v, parts.join(" ")));
+        VirtualColumn::new(function)
+    }
+}
+
+/// Trait implementing window functions to create [VirtualColumn]s for all columns
+pub trait WindowVirtualColumn<T: ColumnType, U: Entity> {
+    /// Value of self in the row `offset` rows before the current row in the window, NULL if there is none
+    fn lag(&self, offset: impl IntoSql<i32>, window: &Window<U>) -> VirtualColumn<Option<T>, U>;
+
+    /// Value of self in the row `offset` rows after the current row in the window, NULL if there is none
+    fn lead(&self, offset: impl IntoSql<i32>, window: &Window<U>) -> VirtualColumn<Option<T>, U>;
+
+    /// Value of self in the first row of the frame of the window
+    fn first_value(&self, window: &Window<U>) -> VirtualColumn<T, U>;
+
+    /// Value of self in the last row of the frame of the window.
+    ///
+    /// Note, that the default frame ends at the current row.
+    fn last_value(&self, window: &Window<U>) -> VirtualColumn<T, U>;
+}
+
+impl<T: ColumnType, U: Entity, R: Column<T, U>> WindowVirtualColumn<T, U> for R {
+    fn lag(&self, offset: impl IntoSql<i32>, window: &Window<U>) -> VirtualColumn<Option<T>, U> {
+        let mut sql = self.get_sql();
+        let offset_sql = offset.into_boxed_sql();
+        sql.modify(|v| format!("LAG({},{})", v, offset_sql.sql));
+        sql.values.extend(offset_sql.values);
+        window.apply(sql)
+    }
+
+    fn lead(&self, offset: impl IntoSql<i32>, window: &Window<U>) -> VirtualColumn<Option<T>, U> {
+        let mut sql = self.get_sql();
+        let offset_sql = offset.into_boxed_sql();
+        sql.modify(|v| format!("LEAD({},{})", v, offset_sql.sql));
+        sql.values.extend(offset_sql.values);
+        window.apply(sql)
+    }
+
+    fn first_value(&self, window: &Window<U>) -> VirtualColumn<T, U> {
+        let mut sql = self.get_sql();
+        sql.modify(|v| format!("FIRST_VALUE({})", v));
+        window.apply(sql)
+    }
+
+    fn last_value(&self, window: &Window<U>) -> VirtualColumn<T, U> {
+        let mut sql = self.get_sql();
+        sql.modify(|v| format!("LAST_VALUE({})", v));
+        window.apply(sql)
+    }
+}
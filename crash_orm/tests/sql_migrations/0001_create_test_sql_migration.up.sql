@@ -0,0 +1 @@
+CREATE TABLE test_sql_migration(id INT4 PRIMARY KEY, name TEXT NOT NULL);
@@ -0,0 +1 @@
+DELETE FROM test_sql_migration;
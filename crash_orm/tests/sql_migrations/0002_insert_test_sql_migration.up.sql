@@ -0,0 +1,2 @@
+INSERT INTO test_sql_migration(id, name) VALUES (1, 'first');
+INSERT INTO test_sql_migration(id, name) VALUES (2, 'second');
@@ -0,0 +1,103 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemArithmetic {
+    id: u32,
+    name: String,
+    price: i32,
+    amount: i32,
+    discount: Option<i32>,
+    weight: f64,
+}
+
+#[tokio::test]
+async fn test_arithmetic() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemArithmetic, conn);
+
+    vec![("apple", 3, 10, None, 0.5), ("pear", 7, 2, Some(2), 1.5), ("plum", -4, 5, Some(1), 2.0)]
+        .into_iter().map(|(name, price, amount, discount, weight)| TestItemArithmeticCreate {
+            name: name.to_string(),
+            price,
+            amount,
+            discount,
+            weight,
+        }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    // Select
+    let total = TestItemArithmeticColumn::PRICE.sub(TestItemArithmeticColumn::DISCOUNT.coalesce(0)).mul(TestItemArithmeticColumn::AMOUNT);
+    let results = TestItemArithmetic::select_query::<(i32, i32, i32, i32, i32, i32)>(&[
+        &total,
+        &TestItemArithmeticColumn::PRICE.add(1),
+        &TestItemArithmeticColumn::AMOUNT.div(3),
+        &TestItemArithmeticColumn::AMOUNT.modulo(3),
+        &TestItemArithmeticColumn::PRICE.abs(),
+        &TestItemArithmeticColumn::PRICE.neg(),
+    ])
+        .order(&TestItemArithmeticColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(30, 4, 3, 1, 3, -3), (10, 8, 0, 2, 7, -7), (-25, -3, 1, 2, 4, 4)]);
+
+    let results = TestItemArithmetic::select_query::<(f64, f64, f64)>(&[
+        &TestItemArithmeticColumn::PRICE.power(2.0),
+        &TestItemArithmeticColumn::WEIGHT.power(2.0),
+        &TestItemArithmeticColumn::WEIGHT.mul(2.0).sub(TestItemArithmeticColumn::WEIGHT),
+    ])
+        .order(&TestItemArithmeticColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(9.0, 0.25, 0.5), (49.0, 2.25, 1.5), (16.0, 4.0, 2.0)]);
+
+    // Null handling
+    let results = TestItemArithmetic::select_query::<(Option<i32>, i32, i32)>(&[
+        &TestItemArithmeticColumn::DISCOUNT.nullif(2),
+        &TestItemArithmeticColumn::PRICE.greatest(TestItemArithmeticColumn::AMOUNT),
+        &TestItemArithmeticColumn::PRICE.least(0),
+    ])
+        .order(&TestItemArithmeticColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(None, 10, 0), (None, 7, 0), (Some(1), 5, -4)]);
+
+    // Case
+    let category = Case::new()
+        .when(TestItemArithmeticColumn::PRICE.less_than(0), "refund")
+        .when(TestItemArithmeticColumn::PRICE.greater_than(5), "expensive")
+        .otherwise(TestItemArithmeticColumn::NAME);
+    let bonus = Case::new()
+        .when(TestItemArithmeticColumn::AMOUNT.greater_equal(5), TestItemArithmeticColumn::AMOUNT.mul(2))
+        .end();
+    let results = TestItemArithmetic::select_query::<(String, Option<i32>)>(&[&category, &bonus])
+        .order(&TestItemArithmeticColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("apple"), Some(20)),
+        (String::from("expensive"), None),
+        (String::from("refund"), Some(10)),
+    ]);
+
+    // Where and order
+    let results = TestItemArithmetic::select_query::<SingleResult<String>>(&[&TestItemArithmeticColumn::NAME])
+        .condition(TestItemArithmeticColumn::PRICE.mul(TestItemArithmeticColumn::AMOUNT).greater_than(0))
+        .order(&Case::new().when(TestItemArithmeticColumn::NAME.equals("pear"), 0).otherwise(1), OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.into_iter().map(|v| v.clone()).collect::<Vec<_>>(), vec!["pear", "apple"]);
+
+    // Update
+    TestItemArithmetic::update_query()
+        .set(&TestItemArithmeticColumn::AMOUNT, TestItemArithmeticColumn::AMOUNT.sub(1))
+        .set(&TestItemArithmeticColumn::DISCOUNT, Case::new().when(TestItemArithmeticColumn::PRICE.greater_than(5), 3).end())
+        .set(&TestItemArithmeticColumn::NAME, TestItemArithmeticColumn::NAME.uppercase())
+        .condition(TestItemArithmeticColumn::AMOUNT.greater_than(2))
+        .execute(&conn).await.unwrap();
+    TestItemArithmetic::update_query()
+        .set(&TestItemArithmeticColumn::WEIGHT, 1.0)
+        .execute(&conn).await.unwrap();
+
+    let results = TestItemArithmetic::query()
+        .order(&TestItemArithmeticColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(
+        results.iter().map(|v| (&*v.name, v.amount, v.discount, v.weight)).collect::<Vec<_>>(),
+        vec![("APPLE", 9, None, 1.0), ("pear", 2, Some(2), 1.0), ("PLUM", 4, None, 1.0)],
+    );
+}
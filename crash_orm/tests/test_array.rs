@@ -0,0 +1,83 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemArray {
+    id: u32,
+    tags: Vec<String>,
+    scores: Option<Vec<i32>>,
+    data: Vec<u8>,
+}
+
+#[tokio::test]
+async fn test_array() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemArray, conn);
+
+    let columns = conn.query_many(
+        "SELECT column_name::text, udt_name::text FROM information_schema.columns \
+        WHERE table_name = 'test_item_array' ORDER BY ordinal_position",
+        &[],
+    ).await.unwrap().iter().map(|row| (row.get(0), row.get(1))).collect::<Vec<(String, String)>>();
+    assert_eq!(columns, vec![
+        (String::from("id"), String::from("oid")),
+        (String::from("tags"), String::from("_text")),
+        (String::from("scores"), String::from("_int4")),
+        (String::from("data"), String::from("bytea")),
+    ]);
+
+    vec![
+        TestItemArrayCreate {
+            tags: vec![String::from("a"), String::from("b")],
+            scores: Some(vec![1, 2, 3]),
+            data: vec![1, 2],
+        },
+        TestItemArrayCreate {
+            tags: vec![String::from("b"), String::from("c")],
+            scores: None,
+            data: vec![],
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::TAGS.contains(vec![String::from("a"), String::from("b")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].scores, Some(vec![1, 2, 3]));
+    assert_eq!(results[0].data, vec![1, 2]);
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::TAGS.contained_by(vec![String::from("b"), String::from("c"), String::from("d")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].scores, None);
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::TAGS.overlaps(vec![String::from("c"), String::from("a")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::SCORES.any_equals(2))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::TAGS.equals(vec![String::from("b"), String::from("c")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemArray::query()
+        .condition(TestItemArrayColumn::TAGS.array_length().greater_than(1))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let mut tags = TestItemArray::select_query::<(String,)>(&[&TestItemArrayColumn::TAGS.unnest()])
+        .fetch(&conn).await.unwrap().into_iter().map(|(tag,)| tag).collect::<Vec<String>>();
+    tags.sort();
+    assert_eq!(tags, vec!["a", "b", "b", "c"]);
+
+    let ids = TestItemArray::select_query::<(Vec<u32>,)>(&[&TestItemArrayColumn::ID.array_agg(false)])
+        .fetch_single(&conn).await.unwrap().unwrap().0;
+    assert_eq!(ids.len(), 2);
+}
@@ -0,0 +1,30 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemBitVarying {
+    id: u32,
+    flags: BitVarying,
+}
+
+#[tokio::test]
+async fn test_bit_varying() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemBitVarying, conn);
+
+    let flags = [true, false, true, true, false, false, false, false, true].into_iter().collect::<BitVarying>();
+    TestItemBitVaryingCreate { flags: flags.clone() }.insert(&conn).await.unwrap();
+    TestItemBitVaryingCreate { flags: BitVarying::new() }.insert(&conn).await.unwrap();
+
+    let text = conn.query_single("SELECT flags::text FROM test_item_bit_varying WHERE flags = $1", &[&flags])
+        .await.unwrap().unwrap().get::<_, String>(0);
+    assert_eq!(text, "101100001");
+
+    let results = TestItemBitVarying::query()
+        .condition(TestItemBitVaryingColumn::FLAGS.equals(flags.clone()))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].flags.len(), 9);
+    assert_eq!(results[0].flags.get(8), Some(true));
+    assert_eq!(results[0].flags.iter().collect::<Vec<bool>>(), flags.iter().collect::<Vec<bool>>());
+}
@@ -0,0 +1,25 @@
+use bytes::Bytes;
+
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemBytea {
+    id: u32,
+    data: Bytea,
+}
+
+#[tokio::test]
+async fn test_bytea() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemBytea, conn);
+
+    let data = Bytea::from(Bytes::from_static(b"\x00\x01crash"));
+    TestItemByteaCreate { data: data.clone() }.insert(&conn).await.unwrap();
+
+    let results = TestItemBytea::query()
+        .condition(TestItemByteaColumn::DATA.equals(data))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(&results[0].data[2..], b"crash");
+}
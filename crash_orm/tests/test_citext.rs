@@ -0,0 +1,27 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemCitext {
+    id: u32,
+    email: Citext,
+}
+
+#[tokio::test]
+async fn test_citext() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemCitext, conn);
+
+    TestItemCitextCreate { email: Citext::from("Test@Example.com") }.insert(&conn).await.unwrap();
+
+    let results = TestItemCitext::query()
+        .condition(TestItemCitextColumn::EMAIL.equals(Citext::from("test@example.COM")))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(*results[0].email, "Test@Example.com");
+
+    let results = TestItemCitext::query()
+        .condition(TestItemCitextColumn::EMAIL.like("test@%"))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
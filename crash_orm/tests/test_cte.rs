@@ -0,0 +1,96 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemCte {
+    id: u32,
+    name: String,
+    parent: Option<ManyToOne<TestItemCte, u32>>,
+}
+
+#[derive(ResultMapping, Debug, PartialEq)]
+struct CategoryPath {
+    name: String,
+    depth: i32,
+}
+
+#[tokio::test]
+async fn test_cte() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemCte, conn);
+
+    // 1 root -> 2 books -> 4 fiction -> 5 scifi, 1 root -> 3 music
+    for (name, parent) in [("root", None), ("books", Some(1)), ("music", Some(1)), ("fiction", Some(2)), ("scifi", Some(4))] {
+        TestItemCteCreate {
+            name: name.to_string(),
+            parent: parent.map(ManyToOne::new),
+        }.insert(&conn).await.unwrap();
+    }
+
+    // Non-recursive
+    let children = Cte::<TestItemCte>::new("children");
+    let results: Vec<TestItemCte> = TestItemCte::query()
+        .with_cte("children", TestItemCte::query().condition(TestItemCteColumn::PARENT.is_not_null()))
+        .condition(TestItemCteColumn::ID.in_query(children.select_query::<SingleResult<u32>>(&[&TestItemCteColumn::PARENT_PRIMARY])))
+        .order(&TestItemCteColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| &*v.name).collect::<Vec<_>>(), vec!["root", "books", "fiction"]);
+
+    let results: Vec<TestItemCte> = children.query()
+        .with_cte("children", TestItemCte::query().condition(TestItemCteColumn::NAME.like("%i%")))
+        .condition(TestItemCteColumn::NAME.not_equals("music"))
+        .order(&TestItemCteColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| &*v.name).collect::<Vec<_>>(), vec!["fiction", "scifi"]);
+
+    // Descendants
+    let tree = Cte::<TestItemCte>::new("tree");
+    let results: Vec<WithDepth<TestItemCte>> = tree
+        .descendants(&TestItemCteColumn::ID, &TestItemCteColumn::PARENT_PRIMARY, 2)
+        .query()
+        .order(&tree.depth(), OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| (&*v.entity.name, v.depth)).collect::<Vec<_>>(), vec![("books", 0), ("fiction", 1), ("scifi", 2)]);
+
+    let results: Vec<TestItemCte> = tree
+        .descendants(&TestItemCteColumn::ID, &TestItemCteColumn::PARENT_PRIMARY, 1)
+        .max_depth(1)
+        .query()
+        .condition(tree.depth().greater_than(0))
+        .order(&TestItemCteColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| &*v.name).collect::<Vec<_>>(), vec!["books", "music"]);
+
+    // Ancestors
+    let results = tree
+        .ancestors(&TestItemCteColumn::ID, &TestItemCteColumn::PARENT_PRIMARY, 5)
+        .select_query::<CategoryPath>(&[&TestItemCteColumn::NAME, &tree.depth()])
+        .order(&tree.depth(), OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        CategoryPath { name: String::from("root"), depth: 3 },
+        CategoryPath { name: String::from("books"), depth: 2 },
+        CategoryPath { name: String::from("fiction"), depth: 1 },
+        CategoryPath { name: String::from("scifi"), depth: 0 },
+    ]);
+
+    // Custom recursion with parameters in anchor and step
+    let results = tree
+        .recursive(
+            TestItemCteColumn::NAME.equals("root"),
+            TestItemCteColumn::PARENT_PRIMARY.equals(tree.column(&TestItemCteColumn::ID))
+                .and(TestItemCteColumn::NAME.not_equals("music")),
+        )
+        .select_query::<SingleResult<i64>>(&[&TestItemCteColumn::ID.count_column(false)])
+        .condition(TestItemCteColumn::NAME.not_equals("scifi"))
+        .fetch_single(&conn).await.unwrap();
+    assert_eq!(*results.unwrap(), 3);
+
+    // Recursive expression as part of another query
+    let results: Vec<TestItemCte> = TestItemCte::query()
+        .with_recursive_cte(tree.ancestors(&TestItemCteColumn::ID, &TestItemCteColumn::PARENT_PRIMARY, 4))
+        .condition(TestItemCteColumn::PARENT_PRIMARY.in_query(tree.select_query::<SingleResult<u32>>(&[&TestItemCteColumn::ID])))
+        .order(&TestItemCteColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| &*v.name).collect::<Vec<_>>(), vec!["books", "music", "fiction", "scifi"]);
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemDateTime {
+    id: u32,
+    created: DateTime<Utc>,
+    day: NaiveDate,
+    local: NaiveDateTime,
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+}
+
+#[tokio::test]
+async fn test_date_time() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemDateTime, conn);
+
+    let now = Utc::now();
+    vec![
+        (utc(2024, 3, 4, 10, 30), date(2024, 3, 4)),
+        (utc(2024, 3, 6, 23, 15), date(2024, 3, 10)),
+        (now - chrono::Duration::hours(1), now.date_naive()),
+    ].into_iter().map(|(created, day)| TestItemDateTimeCreate {
+        created,
+        day,
+        local: created.naive_utc(),
+    }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    // Group by week
+    let week = TestItemDateTimeColumn::CREATED.date_trunc(DateTimeField::Week);
+    let results = TestItemDateTime::select_query::<(DateTime<Utc>, i64)>(&[&week, &TestItemDateTimeColumn::ID.count_column(false)])
+        .condition(TestItemDateTimeColumn::CREATED.less_than(utc(2025, 1, 1, 0, 0)))
+        .group_by(&week)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(utc(2024, 3, 4, 0, 0), 2)]);
+
+    let results = TestItemDateTime::select_query::<(f64, f64, NaiveDate, NaiveDateTime)>(&[
+        &TestItemDateTimeColumn::CREATED.extract(DateTimeField::Hour),
+        &TestItemDateTimeColumn::DAY.extract(DateTimeField::IsoDayOfWeek),
+        &TestItemDateTimeColumn::DAY.date_trunc(DateTimeField::Month),
+        &TestItemDateTimeColumn::LOCAL.date_trunc(DateTimeField::Day),
+    ])
+        .order(&TestItemDateTimeColumn::ID, OrderDirection::ASC)
+        .limit(2)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (10.0, 1.0, date(2024, 3, 1), date(2024, 3, 4).and_hms_opt(0, 0, 0).unwrap()),
+        (23.0, 7.0, date(2024, 3, 1), date(2024, 3, 6).and_hms_opt(0, 0, 0).unwrap()),
+    ]);
+
+    // Time zones
+    let results = TestItemDateTime::select_query::<(NaiveDateTime, DateTime<Utc>)>(&[
+        &TestItemDateTimeColumn::CREATED.at_time_zone("Europe/Berlin"),
+        &TestItemDateTimeColumn::LOCAL.at_time_zone("Europe/Berlin"),
+    ])
+        .condition(TestItemDateTimeColumn::ID.equals(1))
+        .fetch_single(&conn).await.unwrap();
+    assert_eq!(results, Some((date(2024, 3, 4).and_hms_opt(11, 30, 0).unwrap(), utc(2024, 3, 4, 9, 30))));
+
+    // Intervals
+    let results = TestItemDateTime::select_query::<(NaiveDate, DateTime<Utc>, Interval)>(&[
+        &TestItemDateTimeColumn::DAY.add_interval(Interval::new(1, 2, 0)),
+        &TestItemDateTimeColumn::CREATED.sub_interval(Interval::from(Duration::from_secs(3600))),
+        &TestItemDateTimeColumn::CREATED.age(utc(2024, 1, 1, 0, 0)),
+    ])
+        .condition(TestItemDateTimeColumn::ID.equals(1))
+        .fetch_single(&conn).await.unwrap();
+    assert_eq!(results, Some((date(2024, 4, 6), utc(2024, 3, 4, 9, 30), Interval::new(2, 3, 37_800_000_000))));
+
+    // Conditions
+    let ids = |results: Vec<TestItemDateTime>| results.into_iter().map(|v| v.id).collect::<Vec<_>>();
+    let results = TestItemDateTime::query()
+        .condition(TestItemDateTimeColumn::CREATED.within_last(Duration::from_secs(7200)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(ids(results), vec![3]);
+
+    let results = TestItemDateTime::query()
+        .condition(TestItemDateTimeColumn::LOCAL.within_last(Duration::from_secs(7200))
+            .and(TestItemDateTimeColumn::DAY.within_last(Duration::from_secs(86400))))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(ids(results), vec![3]);
+
+    let results = TestItemDateTime::query()
+        .condition(TestItemDateTimeColumn::CREATED.on_date(date(2024, 3, 6))
+            .or(TestItemDateTimeColumn::LOCAL.on_date(date(2024, 3, 4))))
+        .order(&TestItemDateTimeColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(ids(results), vec![1, 2]);
+
+    let results = TestItemDateTime::query()
+        .condition(TestItemDateTimeColumn::DAY.equals(VirtualColumn::<NaiveDate, TestItemDateTime>::current_date())
+            .and(TestItemDateTimeColumn::CREATED.less_than(VirtualColumn::<DateTime<Utc>, TestItemDateTime>::now())))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(ids(results), vec![3]);
+
+    let result = TestItemDateTime::select_query::<SingleResult<DateTime<Utc>>>(&[&VirtualColumn::<DateTime<Utc>, TestItemDateTime>::now()])
+        .fetch_single(&conn).await.unwrap().unwrap();
+    assert!((*result - now).num_seconds().abs() < 60);
+
+    // Update
+    TestItemDateTime::update_query()
+        .set(&TestItemDateTimeColumn::DAY, TestItemDateTimeColumn::DAY.date_trunc(DateTimeField::Year))
+        .condition(TestItemDateTimeColumn::ID.equals(2))
+        .execute(&conn).await.unwrap();
+    let result = TestItemDateTime::get_by_primary(&conn, 2).await.unwrap().unwrap();
+    assert_eq!(result.day, date(2024, 1, 1));
+}
@@ -0,0 +1,99 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(DbEnum, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum TestEnumStatus {
+    Active,
+    #[db_enum(name = "disabled")]
+    Inactive,
+    PendingReview,
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemDbEnum {
+    id: u32,
+    status: TestEnumStatus,
+    previous_status: Option<TestEnumStatus>,
+}
+
+#[derive(DbEnum, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[db_enum(name = "test_enum_migrated")]
+pub enum TestEnumMigration {
+    First,
+    Second,
+    Third,
+}
+
+#[test]
+fn test_db_enum_labels() {
+    assert_eq!(TestEnumStatus::SQL_TYPE, "test_enum_status");
+    assert_eq!(TestEnumStatus::VARIANTS, &["active", "disabled", "pending_review"]);
+    assert_eq!(TestEnumStatus::Inactive.to_label(), "disabled");
+    assert_eq!(TestEnumStatus::from_label("pending_review"), Some(TestEnumStatus::PendingReview));
+    assert_eq!(TestEnumStatus::from_label("inactive"), None);
+}
+
+#[tokio::test]
+async fn test_db_enum() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemDbEnum, conn);
+    assert!(TestEnumStatus::type_exists(&conn).await.unwrap());
+
+    let item = TestItemDbEnumCreate {
+        status: TestEnumStatus::Inactive,
+        previous_status: Some(TestEnumStatus::Active),
+    }.insert(&conn).await.unwrap();
+    TestItemDbEnumCreate {
+        status: TestEnumStatus::PendingReview,
+        previous_status: None,
+    }.insert(&conn).await.unwrap();
+
+    let fetched = TestItemDbEnum::get_by_primary(&conn, item.id).await.unwrap().unwrap();
+    assert_eq!(fetched.status, TestEnumStatus::Inactive);
+    assert_eq!(fetched.previous_status, Some(TestEnumStatus::Active));
+
+    let label = conn.query_single("SELECT status::text FROM test_item_db_enum WHERE id = $1", &[&item.id])
+        .await.unwrap().unwrap().get::<_, String>(0);
+    assert_eq!(label, "disabled");
+
+    let results = TestItemDbEnum::query()
+        .condition(TestItemDbEnumColumn::STATUS.equals(TestEnumStatus::PendingReview))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].previous_status, None);
+
+    let results = TestItemDbEnum::query()
+        .condition(TestItemDbEnumColumn::STATUS.in_vec(vec![TestEnumStatus::Active, TestEnumStatus::Inactive]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, item.id);
+
+    let results = TestItemDbEnum::query()
+        .condition(TestItemDbEnumColumn::PREVIOUS_STATUS.equals(TestEnumStatus::Active))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[tokio::test]
+async fn test_db_enum_migration() {
+    let conn = setup_test_connection().await;
+    TestEnumMigration::drop_type(&conn).await.unwrap();
+    assert!(!TestEnumMigration::type_exists(&conn).await.unwrap());
+
+    conn.execute_query("CREATE TYPE test_enum_migrated AS ENUM ('second')", &[]).await.unwrap();
+    TestEnumMigration::add_missing_values(&conn).await.unwrap();
+
+    let labels = conn.query_many(
+        "SELECT unnest(enum_range(NULL::test_enum_migrated))::text",
+        &[],
+    ).await.unwrap().iter().map(|row| row.get::<_, String>(0)).collect::<Vec<String>>();
+    assert_eq!(labels, vec!["first", "second", "third"]);
+
+    TestEnumMigration::add_value(&conn, "third").await.unwrap();
+    TestEnumMigration::add_value(&conn, "fourth").await.unwrap();
+    let row = conn.query_single("SELECT 'fourth'::test_enum_migrated::text", &[]).await.unwrap().unwrap();
+    assert_eq!(row.get::<_, String>(0), "fourth");
+
+    TestEnumMigration::drop_type(&conn).await.unwrap();
+    assert!(!TestEnumMigration::type_exists(&conn).await.unwrap());
+}
@@ -0,0 +1,181 @@
+use crash_orm::postgres::types::Type;
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+const DRY_RUN_TABLE_NAME: &str = "test_dry_run";
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemDryRun {
+    id: u32,
+    name: String,
+}
+
+struct DryRunMigrationManager;
+
+impl CrashOrmMigrationManager for DryRunMigrationManager {
+    fn get_migrations() -> Vec<Box<dyn Migration>> {
+        vec![
+            Box::new(DryRunMigration),
+            Box::new(SqlMigration::new("test_dry_run_sql", "ALTER TABLE test_dry_run ADD COLUMN name TEXT NULL;")
+                .with_down("ALTER TABLE test_dry_run DROP COLUMN name;")),
+        ]
+    }
+}
+
+struct DryRunMigration;
+
+#[async_trait]
+impl Migration for DryRunMigration {
+    async fn up(&self, conn: &CrashOrmDatabaseConnection) -> Result<()> {
+        TableDefinition::new(DRY_RUN_TABLE_NAME)
+            .add_column(ColumnDefinition::new("id", Type::INT4, false).primary())?
+            .apply(conn).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, conn: &CrashOrmDatabaseConnection) -> Result<()> {
+        TableDefinition::drop_table(conn, DRY_RUN_TABLE_NAME).await?;
+
+        Ok(())
+    }
+
+    fn get_name(&self) -> &str {
+        DRY_RUN_TABLE_NAME
+    }
+}
+
+#[tokio::test]
+async fn test_recording_connection() {
+    let conn = setup_test_connection().await;
+    let recording = conn.recording();
+    assert!(recording.is_recording());
+    assert!(!conn.is_recording());
+
+    let row = recording.query_single("SELECT $1::TEXT", &[&"hello"]).await.unwrap().unwrap();
+    assert_eq!(row.get::<_, String>(0), "hello");
+
+    let modified = recording.execute_query("DELETE FROM test_dry_run_missing WHERE id = $1", &[&1i32]).await.unwrap();
+    assert_eq!(modified, 0);
+
+    let statements = recording.take_recorded_statements();
+    assert_eq!(statements.len(), 1);
+    assert_eq!(statements[0].to_string(), "DELETE FROM test_dry_run_missing WHERE id = $1; -- $1 = 1");
+    assert!(recording.take_recorded_statements().is_empty());
+}
+
+#[tokio::test]
+async fn test_recording_statement_classification() {
+    let conn = setup_test_connection().await;
+    let recording = conn.recording();
+
+    let row = recording.query_single("WITH v AS (SELECT $1::TEXT AS x) SELECT x FROM v", &[&"hello"]).await.unwrap().unwrap();
+    assert_eq!(row.get::<_, String>(0), "hello");
+    let row = recording.query_single("SELECT 'nextval' FOR UPDATE", &[]).await.unwrap();
+    assert!(row.is_some());
+    assert!(recording.take_recorded_statements().is_empty());
+
+    // Executing these would fail, as neither the sequence nor the table exist
+    assert!(recording.query_single("SELECT nextval('test_dry_run_missing_seq')", &[]).await.unwrap().is_none());
+    assert!(recording.query_single("select SETVAL('test_dry_run_missing_seq', 5)", &[]).await.unwrap().is_none());
+    assert!(recording.query_many("WITH moved AS (DELETE FROM test_dry_run_missing RETURNING *) SELECT * FROM moved", &[]).await.unwrap().is_empty());
+    assert!(recording.query_many("SELECT * INTO test_dry_run_copy FROM test_dry_run_missing", &[]).await.unwrap().is_empty());
+    assert_eq!(recording.take_recorded_statements().len(), 4);
+}
+
+#[tokio::test]
+async fn test_recording_destructive_statements() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemDryRun, conn);
+    let recording = conn.recording();
+
+    let statements = [
+        "SELECT 1; DROP TABLE test_item_dry_run;",
+        "SELECT 1; ALTER TABLE test_item_dry_run ADD b int4",
+        "SELECT 1; -- don't\nDELETE FROM test_item_dry_run;",
+    ];
+    for statement in statements {
+        assert!(recording.query_many(statement, &[]).await.unwrap().is_empty());
+        assert_eq!(recording.execute_query(statement, &[]).await.unwrap(), 0);
+        recording.execute_batch(statement).await.unwrap();
+    }
+    assert_eq!(recording.take_recorded_statements().len(), 9);
+
+    recording.execute_batch("SELECT 1").await.unwrap();
+    assert!(recording.query_single("SELECT 1 FROM pg_class /* DROP TABLE; */ WHERE relname = 'x' -- DELETE;", &[]).await.unwrap().is_none());
+    assert!(recording.query_single("SELECT 1 /* nested /* ; */ DROP */ -- ;\n", &[]).await.unwrap().is_some());
+    assert!(recording.query_single("ALTER TABLE test_item_dry_run ADD b int4", &[]).await.unwrap().is_none());
+    assert_eq!(recording.take_recorded_statements().len(), 2);
+
+    assert_eq!(TestItemDryRun::count(&conn).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_recording_insert() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemDryRun, conn);
+    let recording = conn.recording();
+
+    let entity = TestItemDryRunCreate {
+        name: String::from("test"),
+    }.insert(&recording).await.unwrap();
+    assert_eq!(entity.id, 0);
+
+    let statements = recording.take_recorded_statements();
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0].statement.starts_with("INSERT INTO public.test_item_dry_run"));
+    assert_eq!(TestItemDryRun::count(&recording).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_recording_update_remove() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemDryRun, conn);
+    let recording = conn.recording();
+
+    let mut entity = TestItemDryRunCreate {
+        name: String::from("test"),
+    }.insert(&recording).await.unwrap();
+    entity.name = String::from("changed");
+    entity.update(&recording).await.unwrap();
+    entity.remove(&recording).await.unwrap();
+
+    let statements = recording.take_recorded_statements();
+    assert_eq!(statements.len(), 3);
+    assert!(statements[1].statement.starts_with("UPDATE public.test_item_dry_run"));
+    assert!(statements[2].statement.starts_with("DELETE FROM public.test_item_dry_run"));
+    assert!(matches!(entity.update(&conn).await, Err(Error::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_dry_run() {
+    let conn = setup_test_connection().await;
+    TableDefinition::drop_table(&conn, DRY_RUN_TABLE_NAME).await.unwrap();
+    CrashOrmMigrationRecord::create_table_if_not_exists(&conn).await.unwrap();
+    CrashOrmMigrationRecord::delete()
+        .condition(CrashOrmMigrationRecordColumn::NAME.like("test_dry_run%"))
+        .execute(&conn).await.unwrap();
+
+    let script = DryRunMigrationManager::dry_run(&conn, MigrationAction::Up).await.unwrap();
+    assert_eq!(script, "-- up: test_dry_run\n\
+        CREATE TABLE public.test_dry_run(id int4 NOT NULL,PRIMARY KEY (id));\n\
+        -- up: test_dry_run_sql\n\
+        ALTER TABLE test_dry_run ADD COLUMN name TEXT NULL;\n");
+    assert!(TableDefinition::load_from_database(&conn, DRY_RUN_TABLE_NAME).await.is_err());
+
+    DryRunMigrationManager::migrate_up(&conn).await.unwrap();
+    assert_eq!(DryRunMigrationManager::dry_run(&conn, MigrationAction::Up).await.unwrap(), "");
+
+    let script = DryRunMigrationManager::dry_run(&conn, MigrationAction::DownPrev).await.unwrap();
+    assert_eq!(script, "-- down: test_dry_run_sql\nALTER TABLE test_dry_run DROP COLUMN name;\n");
+
+    let script = DryRunMigrationManager::dry_run(&conn, MigrationAction::DownTo(DRY_RUN_TABLE_NAME.to_string())).await.unwrap();
+    assert_eq!(script, "-- down: test_dry_run_sql\n\
+        ALTER TABLE test_dry_run DROP COLUMN name;\n\
+        -- down: test_dry_run\n\
+        DROP TABLE IF EXISTS test_dry_run CASCADE;\n");
+    assert!(TableDefinition::load_from_database(&conn, DRY_RUN_TABLE_NAME).await.is_ok());
+
+    DryRunMigrationManager::migrate_down_to(&conn, DRY_RUN_TABLE_NAME).await.unwrap();
+    assert!(TableDefinition::load_from_database(&conn, DRY_RUN_TABLE_NAME).await.is_err());
+}
@@ -0,0 +1,52 @@
+use crash_orm::prelude::*;
+use crash_orm::postgres::error::SqlState;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemError {
+    id: u32,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_not_found() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemError, conn);
+
+    let item = TestItemErrorCreate { name: String::from("test") }.insert(&conn).await.unwrap();
+    item.remove(&conn).await.unwrap();
+
+    let result = item.update(&conn).await;
+    assert!(matches!(result, Err(Error::NotFound { ref statement }) if statement.starts_with("UPDATE public.test_item_error")));
+    assert!(matches!(item.remove(&conn).await, Err(Error::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_constraint_violations() {
+    let conn = setup_test_connection().await;
+    conn.execute_batch("DROP TABLE IF EXISTS test_error_child; DROP TABLE IF EXISTS test_error_parent; \
+        CREATE TABLE test_error_parent(id int4 PRIMARY KEY, value int4 CONSTRAINT test_error_positive CHECK (value > 0)); \
+        CREATE TABLE test_error_child(id int4 PRIMARY KEY, parent int4 CONSTRAINT test_error_fk REFERENCES test_error_parent(id))").await.unwrap();
+
+    conn.execute_query("INSERT INTO test_error_parent VALUES (1, 1)", &[]).await.unwrap();
+
+    let statement = "INSERT INTO test_error_parent VALUES (1, 1)";
+    let error = conn.execute_query(statement, &[]).await.unwrap_err();
+    assert!(matches!(error, Error::UniqueViolation { ref constraint, .. } if constraint.as_deref() == Some("test_error_parent_pkey")));
+    assert_eq!(error.statement(), Some(statement));
+    assert_eq!(error.code(), Some(&SqlState::UNIQUE_VIOLATION));
+    assert!(error.to_string().contains(statement));
+
+    let error = conn.execute_query("INSERT INTO test_error_parent VALUES (2, -1)", &[]).await.unwrap_err();
+    assert!(matches!(error, Error::CheckViolation { ref constraint, .. } if constraint.as_deref() == Some("test_error_positive")));
+
+    let error = conn.query_single("INSERT INTO test_error_child VALUES (1, 2) RETURNING id", &[]).await.unwrap_err();
+    assert!(matches!(error, Error::ForeignKeyViolation { ref constraint, .. } if constraint.as_deref() == Some("test_error_fk")));
+
+    let error = conn.query_many("SELECT * FROM test_error_missing_table", &[]).await.unwrap_err();
+    assert!(matches!(error, Error::Postgres(_)));
+    assert_eq!(error.code(), Some(&SqlState::UNDEFINED_TABLE));
+    assert_eq!(error.statement(), None);
+
+    conn.execute_batch("DROP TABLE test_error_child; DROP TABLE test_error_parent").await.unwrap();
+}
@@ -0,0 +1,117 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+#[index(name = "test_item_full_text_description_idx", using = "gin", expression = "to_tsvector('simple', description)")]
+pub struct TestItemFullText {
+    id: u32,
+    description: String,
+    #[index(using = "gin")]
+    #[generated = "setweight(to_tsvector('english', title), 'A') || to_tsvector('english', description)"]
+    search: TsVector,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_full_text() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemFullText, conn);
+
+    vec![
+        TestItemFullTextCreate {
+            title: String::from("Running shoes"),
+            description: String::from("Light shoes for running on the road"),
+        },
+        TestItemFullTextCreate {
+            title: String::from("Hiking boots"),
+            description: String::from("Waterproof boots, great for running up hills and hiking in the rain"),
+        },
+        TestItemFullTextCreate {
+            title: String::from("Rain jacket"),
+            description: String::from("Keeps you dry in the rain"),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let indexes = conn.query_single(
+        "SELECT COUNT(*) FROM pg_indexes WHERE tablename = 'test_item_full_text' AND indexdef LIKE '%USING gin%'",
+        &[],
+    ).await.unwrap().unwrap().get::<_, i64>(0);
+    assert_eq!(indexes, 2);
+
+    let item = TestItemFullText::get_by_primary(&conn, 1).await.unwrap().unwrap();
+    let shoe = item.search.get("shoe").unwrap();
+    assert_eq!(shoe.positions.iter().map(|p| (p.position, p.weight)).collect::<Vec<_>>(), vec![(2, TsWeight::A), (4, TsWeight::D)]);
+
+    let query = TsQuery::websearch("english", "running -jacket");
+    let results = TestItemFullText::query()
+        .condition(TestItemFullTextColumn::SEARCH.matches(&query))
+        .order(&TestItemFullTextColumn::SEARCH.ts_rank(&query), OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| v.id).collect::<Vec<_>>(), vec![1, 2]);
+
+    let results = TestItemFullText::query()
+        .condition(TestItemFullTextColumn::SEARCH.matches(&TsQuery::phrase("english", "in the rain")))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemFullText::query()
+        .condition(TestItemFullTextColumn::SEARCH.matches(&TsQuery::raw("english", "rain & !jacket")))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, 2);
+
+    let query = TsQuery::plain("english", "boots");
+    let rank = TestItemFullText::select_query::<(f32,)>(&[&TestItemFullTextColumn::SEARCH.ts_rank_cd(&query)])
+        .condition(TestItemFullTextColumn::ID.equals(2))
+        .fetch_single(&conn).await.unwrap().unwrap().0;
+    assert!(rank > 0.0);
+
+    let mut item = TestItemFullText::get_by_primary(&conn, 3).await.unwrap().unwrap();
+    item.title = String::from("Running jacket");
+    item.update(&conn).await.unwrap();
+    let item = TestItemFullText::get_by_primary(&conn, 3).await.unwrap().unwrap();
+    assert!(item.search.get("run").is_some());
+
+    let query = TsQuery::plain("english", "waterproof boot");
+    let results = TestItemFullText::query()
+        .condition(TestItemFullTextColumn::DESCRIPTION.matches(&query))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, 2);
+
+    let headline = TestItemFullText::select_query::<(String,)>(&[&TestItemFullTextColumn::DESCRIPTION.ts_headline(&query)])
+        .condition(TestItemFullTextColumn::DESCRIPTION.matches(&query))
+        .fetch_single(&conn).await.unwrap().unwrap().0;
+    assert!(headline.starts_with("<b>Waterproof</b> <b>boots</b>"));
+
+    let headline = TestItemFullText::select_query::<(String,)>(&[&TestItemFullTextColumn::DESCRIPTION.ts_headline_with_options(&query, "StartSel=[, StopSel=], MaxWords=3, MinWords=1")])
+        .condition(TestItemFullTextColumn::ID.equals(2))
+        .fetch_single(&conn).await.unwrap().unwrap().0;
+    assert_eq!(headline, "[Waterproof] [boots]");
+
+    let vector = TestItemFullText::select_query::<(TsVector,)>(&[&TestItemFullTextColumn::TITLE.to_tsvector("simple")])
+        .condition(TestItemFullTextColumn::ID.equals(1))
+        .fetch_single(&conn).await.unwrap().unwrap().0;
+    assert_eq!(vector.lexemes().iter().map(|v| v.word.as_str()).collect::<Vec<_>>(), vec!["running", "shoes"]);
+
+    let results = TestItemFullText::query()
+        .condition(TestItemFullTextColumn::TITLE.to_tsvector("simple").matches(&TsQuery::plain("simple", "running")))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+}
+
+#[tokio::test]
+async fn test_full_text_ts_vector_roundtrip() {
+    let conn = setup_test_connection().await;
+
+    let vector = TsVector::new(vec![
+        TsLexeme { word: String::from("crash"), positions: vec![TsPosition { position: 3, weight: TsWeight::B }, TsPosition { position: 1, weight: TsWeight::D }] },
+        TsLexeme { word: String::from("orm"), positions: vec![] },
+    ]);
+    let row = conn.query_single("SELECT $1::tsvector::text, $1::tsvector", &[&vector]).await.unwrap().unwrap();
+    assert_eq!(row.get::<_, String>(0), "'crash':1,3B 'orm'");
+
+    let result = row.get::<_, TsVector>(1);
+    assert_eq!(result.get("crash").unwrap().positions.len(), 2);
+    assert!(result.get("orm").unwrap().positions.is_empty());
+}
@@ -48,4 +48,61 @@ async fn test_group_by() {
     assert_eq!(results[1], 1);
 
     assert!(TestItemGroupBy::drop_table(&conn).await.is_ok());
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemHaving {
+    id: u32,
+    name: String,
+    amount: i32,
+}
+
+#[tokio::test]
+async fn test_having() {
+    let conn = setup_test_connection().await;
+
+    if !TestItemHaving::table_exists(&conn).await.unwrap() {
+        TestItemHaving::create_table(&conn).await.unwrap();
+    } else {
+        TestItemHaving::truncate_table(&conn).await.unwrap();
+    }
+
+    vec![
+        TestItemHavingCreate { name: String::from("Alice"), amount: 10 },
+        TestItemHavingCreate { name: String::from("alice"), amount: 20 },
+        TestItemHavingCreate { name: String::from("Bob"), amount: 5 },
+        TestItemHavingCreate { name: String::from("bob"), amount: 1 },
+        TestItemHavingCreate { name: String::from("Carol"), amount: 100 },
+    ].insert_all(&conn).await.unwrap();
+
+    let name = TestItemHavingColumn::NAME.lowercase();
+    let results = TestItemHaving::select_query::<(String, i64)>(&[&name, &TestItemHavingColumn::AMOUNT.sum(false)])
+        .group_by(&name)
+        .having(TestItemHavingColumn::ID.count_column(false).greater_than(1))
+        .order(&name, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("alice"), 30), (String::from("bob"), 6)]);
+
+    // Parameters of condition, grouping and having are resolved in order
+    let results = TestItemHaving::select_query::<(i64,)>(&[&TestItemHavingColumn::AMOUNT.sum(false)])
+        .condition(TestItemHavingColumn::AMOUNT.greater_than(2))
+        .group_by(&TestItemHavingColumn::NAME.lowercase().repeat(2))
+        .having(TestItemHavingColumn::AMOUNT.sum(false).less_than(50i64))
+        .order(&TestItemHavingColumn::AMOUNT.sum(false), OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(30,), (5,)]);
+
+    // A parameterized expression, which is selected and grouped by, is grouped by its position
+    let name = TestItemHavingColumn::NAME.lowercase().repeat(2);
+    let results = TestItemHaving::select_query::<(String, i64)>(&[&name, &TestItemHavingColumn::ID.count_column(false)])
+        .group_by(&TestItemHavingColumn::NAME.lowercase().repeat(2))
+        .order(&name, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("alicealice"), 2),
+        (String::from("bobbob"), 2),
+        (String::from("carolcarol"), 1),
+    ]);
+
+    assert!(TestItemHaving::drop_table(&conn).await.is_ok());
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
-use crash_orm::prelude::{Entity, EntityCreateVec, InQueryColumn, Schema};
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crash_orm::prelude::{Entity, EntityCreateVec, EntityVec, InQueryColumn, ManyToOne, PrimaryKeyEntity, RecordingDatabaseConnection, Schema};
 use crash_orm_test::setup_test_connection;
 
 #[derive(Entity, Debug, Schema)]
@@ -69,3 +72,80 @@ async fn test_in() {
 
     assert!(TestItem14::drop_table(&conn).await.is_ok());
 }
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemInTypes {
+    pub id: u32,
+    pub key: Uuid,
+    pub day: NaiveDate,
+    pub active: bool,
+    pub parent: Option<ManyToOne<TestItemInTypes, u32>>,
+}
+
+#[tokio::test]
+async fn test_in_types() {
+    let conn = setup_test_connection().await;
+
+    if !TestItemInTypes::table_exists(&conn).await.unwrap() {
+        TestItemInTypes::create_table(&conn).await.unwrap();
+    } else {
+        TestItemInTypes::truncate_table(&conn).await.unwrap();
+    }
+
+    let keys = (0..3).map(|_| Uuid::new_v4()).collect::<Vec<Uuid>>();
+    keys.iter().enumerate().map(|(index, key)| TestItemInTypesCreate {
+        key: *key,
+        day: NaiveDate::from_ymd_opt(2024, 1, index as u32 + 1).unwrap(),
+        active: index != 1,
+        parent: Some(ManyToOne::new(index as u32 + 1)),
+    }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::KEY.in_vec(vec![keys[0], keys[2], Uuid::new_v4()]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::DAY.not_in_vec(vec![NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::ACTIVE.in_vec(vec![false]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].key, keys[1]);
+
+    // A large list is bound as a single array parameter
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::PARENT_PRIMARY.in_vec((2..100_000).collect::<Vec<u32>>()))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::ID.in_vec(Vec::<u32>::new()))
+        .fetch(&conn).await.unwrap();
+    assert!(results.is_empty());
+
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::ID.in_vec(vec![TestItemInTypesColumn::PARENT_PRIMARY.outer()]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 3);
+
+    let recording = conn.recording();
+    let results = TestItemInTypes::query()
+        .condition(TestItemInTypesColumn::ID.in_vec(vec![1, 2]))
+        .fetch(&recording).await.unwrap();
+    results.remove_all(&recording).await.unwrap();
+    let statements = recording.take_recorded_statements();
+    assert_eq!(statements.len(), 1);
+    assert_eq!(statements[0].to_string(), "DELETE FROM test_item_in_types WHERE id = ANY($1); -- $1 = [1, 2]");
+
+    results.remove_all(&conn).await.unwrap();
+
+    let remaining = TestItemInTypes::get_all(&conn).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].get_primary(), 3);
+
+    TestItemInTypes::drop_table(&conn).await.unwrap();
+}
\ No newline at end of file
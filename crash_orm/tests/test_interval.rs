@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemInterval {
+    id: u32,
+    duration: Interval,
+}
+
+#[tokio::test]
+async fn test_interval() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemInterval, conn);
+
+    vec![
+        TestItemIntervalCreate { duration: Interval::from(Duration::from_secs(90)) },
+        TestItemIntervalCreate { duration: Interval::new(1, 2, 3) },
+        TestItemIntervalCreate { duration: Interval::from(chrono::TimeDelta::hours(2)) },
+    ].insert_all(&conn).await.unwrap();
+
+    let text = conn.query_single("SELECT duration::text FROM test_item_interval WHERE duration = $1", &[&Interval::new(1, 2, 3)])
+        .await.unwrap().unwrap().get::<_, String>(0);
+    assert_eq!(text, "1 mon 2 days 00:00:00.000003");
+
+    let results = TestItemInterval::query()
+        .condition(TestItemIntervalColumn::DURATION.less_than(Interval::from(Duration::from_secs(3600))))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].duration.to_duration(), Some(Duration::from_secs(90)));
+
+    let results = TestItemInterval::query()
+        .condition(TestItemIntervalColumn::DURATION.in_vec(vec![Interval::new(1, 2, 3), Interval::new(0, 1, 0)]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].duration.to_duration(), None);
+}
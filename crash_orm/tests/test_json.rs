@@ -1,4 +1,5 @@
 use crash_orm::prelude::*;
+use serde_json::json;
 use crash_orm_test::{default_create_table, setup_test_connection};
 
 #[derive(Entity, Schema, Debug)]
@@ -25,4 +26,116 @@ pub async fn test_json() {
     assert_eq!(*results.data, example_json);
 
     TestItemJson::drop_table(&conn).await.unwrap();
-}
\ No newline at end of file
+}
+
+#[derive(Entity, Schema, Debug)]
+pub struct TestItemJsonb {
+    pub id: u32,
+    pub data: serde_json::Value,
+    pub typed: TypedJson<Vec<String>>,
+}
+
+#[tokio::test]
+pub async fn test_jsonb_operators() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemJsonb, conn);
+
+    vec![
+        TestItemJsonbCreate {
+            data: json!({"name": "Alice", "active": true, "count": 3, "address": {"city": "Berlin"}}),
+            typed: TypedJson(vec![String::from("a"), String::from("b")]),
+        },
+        TestItemJsonbCreate {
+            data: json!({"name": "Bob", "active": false, "count": 1, "address": {"city": "Amsterdam"}, "extra": 1}),
+            typed: TypedJson(vec![String::from("c")]),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.contains(json!({"active": true})))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].data["name"], "Alice");
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::TYPED.contains(json!(["c"])))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(*results[0].typed, vec![String::from("c")]);
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.has_key("extra"))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.has_any_key(vec![String::from("extra"), String::from("missing")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.has_all_keys(vec![String::from("name"), String::from("count")]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.matches_json_path("$.count > 2"))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].data["name"], "Alice");
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.json_get_text("name").equals("Bob"))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemJsonb::query()
+        .condition(TestItemJsonbColumn::DATA.json_get("address").json_get_text("city").like("%e%"))
+        .order(&TestItemJsonbColumn::DATA.json_get_path_text(vec![String::from("address"), String::from("city")]), OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].data["name"], "Bob");
+
+    let names = TestItemJsonb::select_query::<(String,)>(&[&TestItemJsonbColumn::DATA.json_get_text("name")])
+        .condition(TestItemJsonbColumn::DATA.json_get("count").equals(json!(1)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(names, vec![(String::from("Bob"),)]);
+}
+
+#[derive(Entity, Schema, Debug)]
+pub struct TestItemJsonbSelect {
+    pub id: u32,
+    pub data: serde_json::Value,
+}
+
+#[tokio::test]
+pub async fn test_jsonb_select_parameters() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemJsonbSelect, conn);
+
+    vec![
+        TestItemJsonbSelectCreate {
+            data: json!({"name": "Alice", "count": 3, "address": {"city": "Berlin"}}),
+        },
+        TestItemJsonbSelectCreate {
+            data: json!({"name": "Bob", "count": 1, "address": {"city": "Amsterdam"}}),
+        },
+        TestItemJsonbSelectCreate {
+            data: json!({"name": "Carol", "count": 1, "address": {"city": "Cologne"}}),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    // Parameters in the select list, the condition and multiple orders are numbered consecutively
+    let results = TestItemJsonbSelect::select_query::<(String, String)>(&[
+        &TestItemJsonbSelectColumn::DATA.json_get_text("name"),
+        &TestItemJsonbSelectColumn::DATA.json_get("address").json_get_text("city"),
+    ])
+        .condition(TestItemJsonbSelectColumn::DATA.json_get_text("name").not_equals("Alice"))
+        .order(&TestItemJsonbSelectColumn::DATA.json_get_text("count"), OrderDirection::ASC)
+        .order(&TestItemJsonbSelectColumn::DATA.json_get_text("name"), OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("Carol"), String::from("Cologne")),
+        (String::from("Bob"), String::from("Amsterdam")),
+    ]);
+}
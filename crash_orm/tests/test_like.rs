@@ -58,5 +58,115 @@ async fn test_like() {
     assert!(results.is_ok());
     assert_eq!(results.unwrap().len(), 1);
 
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.ilike("TEST1234"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.not_ilike("TEST%"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.similar_to("test(123|999)"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.not_similar_to("test1%"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.regex_match("^test[0-9]{4}$"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.regex_match_ignore_case("^TEST"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME1.not_regex_match("4$"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItem11::query()
+        .condition(TestItem11Column::NAME2.starts_with("12").and(TestItem11Column::NAME2.ends_with("34")))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
     assert!(TestItem11::drop_table(&conn).await.is_ok());
 }
+
+#[tokio::test]
+async fn test_like_escaping() {
+    let conn = setup_test_connection().await;
+
+    #[derive(Entity, Debug, Schema)]
+    pub struct TestItemLikeEscape {
+        pub id: u32,
+        pub name: String,
+    }
+
+    if !TestItemLikeEscape::table_exists(&conn).await.unwrap() {
+        TestItemLikeEscape::create_table(&conn).await.unwrap();
+    } else {
+        TestItemLikeEscape::truncate_table(&conn).await.unwrap();
+    }
+
+    vec![
+        TestItemLikeEscapeCreate { name: String::from("50%_off") },
+        TestItemLikeEscapeCreate { name: String::from("500 off") },
+        TestItemLikeEscapeCreate { name: String::from("C:\\temp\\x") },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemLikeEscape::query()
+        .condition(TestItemLikeEscapeColumn::NAME.starts_with("50%"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "50%_off");
+
+    let results = TestItemLikeEscape::query()
+        .condition(TestItemLikeEscapeColumn::NAME.contains_text("_"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemLikeEscape::query()
+        .condition(TestItemLikeEscapeColumn::NAME.ends_with("\\x"))
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemLikeEscape::query()
+        .condition(TestItemLikeEscapeColumn::NAME.contains_text("0 o").not())
+        .fetch(&conn)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    TestItemLikeEscape::drop_table(&conn).await.unwrap();
+}
@@ -0,0 +1,65 @@
+use crash_orm::postgres::types::Type;
+use crash_orm::prelude::*;
+use crash_orm_test::{setup_test_client, setup_test_connection};
+
+const TRANSACTION_TABLE_NAME: &str = "test_migration_transaction";
+
+struct TransactionMigrationManager;
+
+impl<C: DatabaseConnection> CrashOrmMigrationManager<C> for TransactionMigrationManager {
+    fn get_migrations() -> Vec<Box<dyn Migration<C>>> {
+        vec![
+            Box::new(TransactionMigration),
+            Box::new(SqlMigration::new("test_migration_transaction_sql", "ALTER TABLE test_migration_transaction ADD COLUMN name TEXT NULL;")
+                .with_down("ALTER TABLE test_migration_transaction DROP COLUMN name;")),
+        ]
+    }
+}
+
+struct TransactionMigration;
+
+#[async_trait]
+impl<C: DatabaseConnection> Migration<C> for TransactionMigration {
+    async fn up(&self, conn: &C) -> Result<()> {
+        TableDefinition::new(TRANSACTION_TABLE_NAME)
+            .add_column(ColumnDefinition::new("id", Type::INT4, false).primary())?
+            .apply(conn).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, conn: &C) -> Result<()> {
+        TableDefinition::drop_table(conn, TRANSACTION_TABLE_NAME).await?;
+
+        Ok(())
+    }
+
+    fn get_name(&self) -> &str {
+        TRANSACTION_TABLE_NAME
+    }
+}
+
+#[tokio::test]
+async fn test_migration_transaction() {
+    let conn = setup_test_connection().await;
+    TableDefinition::drop_table(&conn, TRANSACTION_TABLE_NAME).await.unwrap();
+    CrashOrmMigrationRecord::create_table_if_not_exists(&conn).await.unwrap();
+    CrashOrmMigrationRecord::delete()
+        .condition(CrashOrmMigrationRecordColumn::NAME.like("test_migration_transaction%"))
+        .execute(&conn).await.unwrap();
+
+    let mut client = setup_test_client().await;
+    let transaction = client.transaction().await.unwrap();
+    TransactionMigrationManager::migrate_up(&transaction).await.unwrap();
+    assert!(TableDefinition::load_from_database(&transaction, TRANSACTION_TABLE_NAME).await.is_ok());
+    transaction.rollback().await.unwrap();
+
+    assert!(TableDefinition::load_from_database(&conn, TRANSACTION_TABLE_NAME).await.is_err());
+    assert_eq!(TransactionMigrationManager::pending_migrations(&conn).await.unwrap().len(), 2);
+
+    TransactionMigrationManager::migrate_up(&client).await.unwrap();
+    assert!(TableDefinition::load_from_database(&conn, TRANSACTION_TABLE_NAME).await.is_ok());
+
+    TransactionMigrationManager::migrate_down_to(&client, TRANSACTION_TABLE_NAME).await.unwrap();
+    assert!(TableDefinition::load_from_database(&conn, TRANSACTION_TABLE_NAME).await.is_err());
+}
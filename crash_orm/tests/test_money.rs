@@ -0,0 +1,30 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemMoney {
+    id: u32,
+    price: Money,
+}
+
+#[tokio::test]
+async fn test_money() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemMoney, conn);
+
+    vec![
+        TestItemMoneyCreate { price: Money(1999) },
+        TestItemMoneyCreate { price: Money(500) },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemMoney::query()
+        .condition(TestItemMoneyColumn::PRICE.greater_than(Money(1000)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].price, Money(1999));
+
+    let results = TestItemMoney::query()
+        .condition(TestItemMoneyColumn::PRICE.equals(Money(500)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
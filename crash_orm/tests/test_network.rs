@@ -0,0 +1,49 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemNetwork {
+    id: u32,
+    address: IpAddr,
+    network: Option<Cidr>,
+}
+
+#[tokio::test]
+async fn test_network() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemNetwork, conn);
+
+    let private = Cidr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
+    assert!(Cidr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8).is_none());
+    assert!(Cidr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 33).is_none());
+
+    vec![
+        TestItemNetworkCreate {
+            address: IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3)),
+            network: Some(private),
+        },
+        TestItemNetworkCreate {
+            address: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            network: Some(Cidr::new(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)), 32).unwrap()),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemNetwork::query()
+        .condition(TestItemNetworkColumn::ADDRESS.is_in_network(private))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].network, Some(private));
+
+    let results = TestItemNetwork::query()
+        .condition(TestItemNetworkColumn::ADDRESS.equals(IpAddr::V6(Ipv6Addr::LOCALHOST)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].network.unwrap().prefix(), 32);
+
+    let results = TestItemNetwork::query()
+        .condition(TestItemNetworkColumn::NETWORK.is_in_network(Cidr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0).unwrap()))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
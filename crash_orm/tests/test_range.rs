@@ -0,0 +1,111 @@
+use std::ops::Bound;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+use rust_decimal::Decimal;
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemRange {
+    id: u32,
+    numbers: Range<i32>,
+    days: Option<Range<NaiveDate>>,
+    amounts: Range<Decimal>,
+}
+
+#[derive(Entity, Debug, Schema)]
+#[exclude(name = "test_booking_no_overlap", columns(during = "&&"))]
+pub struct TestItemBooking {
+    id: u32,
+    during: Range<DateTime<Utc>>,
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+}
+
+fn time(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap()
+}
+
+#[tokio::test]
+async fn test_range() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemRange, conn);
+
+    vec![
+        TestItemRangeCreate {
+            numbers: Range::from(1..10),
+            days: Some(Range::from(date(1)..=date(5))),
+            amounts: Range::from(Decimal::new(15, 1)..),
+        },
+        TestItemRangeCreate {
+            numbers: Range::from(10..20),
+            days: None,
+            amounts: Range::empty(),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.contains_value(5))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].numbers, Range::from(1..10));
+    // discrete ranges are normalized by Postgres
+    assert_eq!(results[0].days, Some(Range::from(date(1)..date(6))));
+    assert_eq!(results[0].amounts, Range::new(Bound::Included(Decimal::new(15, 1)), Bound::Unbounded));
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.contains_range(Range::from(12..=15)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].amounts.is_empty());
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.overlaps(Range::from(5..15)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.adjacent(Range::from(..1)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.strictly_left_of(Range::from(15..)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].numbers, Range::from(1..10));
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.strictly_right_of(Range::from(..=9)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::DAYS.contains_value(date(3)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = TestItemRange::query()
+        .condition(TestItemRangeColumn::NUMBERS.equals(Range::from(10..20)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[tokio::test]
+async fn test_range_exclusion() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemBooking, conn);
+
+    TestItemBookingCreate { during: Range::from(time(8)..time(10)) }.insert(&conn).await.unwrap();
+    TestItemBookingCreate { during: Range::from(time(10)..time(12)) }.insert(&conn).await.unwrap();
+
+    let error = TestItemBookingCreate { during: Range::from(time(9)..time(11)) }.insert(&conn).await.unwrap_err();
+    assert!(matches!(error, Error::ExclusionViolation { ref constraint, .. } if constraint.as_deref() == Some("test_booking_no_overlap")));
+
+    let results = TestItemBooking::query()
+        .condition(TestItemBookingColumn::DURING.contains_value(time(9)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
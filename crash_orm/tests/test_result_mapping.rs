@@ -37,6 +37,30 @@ struct TestMapping {
     sum: i64,
 }
 
+#[derive(ResultMapping)]
+struct TestMappingByName {
+    #[column(name = "total")]
+    sum: i64,
+    max: i32,
+    #[default]
+    missing: Option<i32>,
+    #[flatten]
+    nested: TestMappingNested,
+    #[flatten]
+    first: (i32,),
+}
+
+#[derive(ResultMapping)]
+struct TestMappingNested {
+    min: i32,
+}
+
+#[allow(dead_code)]
+#[derive(ResultMapping, Debug)]
+struct TestMappingWrongType {
+    count: i32,
+}
+
 #[tokio::test]
 async fn test_result_mapping() {
     let conn = setup_test_connection().await;
@@ -51,6 +75,106 @@ async fn test_result_mapping() {
     ]).fetch_single(&conn).await.unwrap().unwrap();
     assert_eq!(result.count, 3);
     assert_eq!(result.sum, 6);
+
+    let result = TestResultMapping::select_query::<TestMappingWrongType>(&[
+        &TestResultMappingColumn::TEST.count_column(true),
+    ]).fetch(&conn).await;
+    match result {
+        Err(Error::RowMapping { column, expected, actual, statement }) => {
+            assert_eq!(column, "count");
+            assert_eq!(expected, "i32");
+            assert_eq!(actual, "int8");
+            assert!(statement.starts_with("SELECT COUNT("));
+        }
+        _ => panic!("Expected row mapping error, got {:?}", result),
+    }
+
+    let result = TestResultMapping::select_query::<TestMappingWrongType>(&[
+        &TestResultMappingColumn::TEST.count_column(true),
+    ]).lenient().fetch(&conn).await.unwrap();
+    assert!(result.is_empty());
+
+    let result = TestResultMapping::select_query::<SingleResult<i64>>(&[
+        &TestResultMappingColumn::NUMBER.sum(true),
+    ]).condition(TestResultMappingColumn::NUMBER.greater_than(10)).fetch_single(&conn).await;
+    assert!(matches!(result, Err(Error::RowMapping { ref actual, .. }) if actual == "NULL"));
     
+    let result = TestResultMapping::select_query::<TestMappingByName>(&[
+        &TestResultMappingColumn::NUMBER.min(),
+        &TestResultMappingColumn::NUMBER.max(),
+        &TestResultMappingColumn::NUMBER.sum(true).alias("total"),
+    ]).fetch_single(&conn).await.unwrap().unwrap();
+    assert_eq!(result.sum, 6);
+    assert_eq!(result.max, 3);
+    assert_eq!(result.missing, None);
+    assert_eq!(result.nested.min, 1);
+    assert_eq!(result.first.0, 1);
+
+    let result = TestResultMapping::select_query::<TestMappingByName>(&[
+        &TestResultMappingColumn::NUMBER.max(),
+    ]).fetch_single(&conn).await;
+    assert!(matches!(result, Err(Error::RowMapping { ref column, ref actual, .. }) if column == "total" && actual == "missing"));
+
+    conn.execute_query("ALTER TABLE test_result_mapping ADD COLUMN added int4", &[]).await.unwrap();
+    conn.execute_query("ALTER TABLE test_result_mapping DROP COLUMN number", &[]).await.unwrap();
+    conn.execute_query("ALTER TABLE test_result_mapping ADD COLUMN number int4 NOT NULL DEFAULT 5", &[]).await.unwrap();
+    let results = TestResultMapping::get_all(&conn).await.unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r.number == 5));
+
     TestResultMapping::drop_table(&conn).await.unwrap();
-}
\ No newline at end of file
+}
+
+#[derive(Entity, Debug, Schema)]
+struct TestResultMappingAdHoc {
+    id: u32,
+    name: String,
+    amount: Option<i64>,
+}
+
+#[derive(serde::Deserialize)]
+struct TestReport {
+    name: String,
+    amount: Option<i64>,
+}
+
+#[tokio::test]
+async fn test_ad_hoc_result_mapping() {
+    let conn = setup_test_connection().await;
+
+    default_create_table!(TestResultMappingAdHoc, conn);
+
+    vec![
+        TestResultMappingAdHocCreate { name: String::from("a"), amount: Some(5) },
+        TestResultMappingAdHocCreate { name: String::from("b"), amount: None },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestResultMappingAdHoc::select_query::<(String, Option<i64>)>(&[
+        &TestResultMappingAdHocColumn::NAME,
+        &TestResultMappingAdHocColumn::AMOUNT,
+    ]).order(&TestResultMappingAdHocColumn::NAME, OrderDirection::ASC).fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("a"), Some(5)), (String::from("b"), None)]);
+
+    let result = TestResultMappingAdHoc::select_query::<(String,)>(&[
+        &TestResultMappingAdHocColumn::AMOUNT,
+    ]).fetch(&conn).await;
+    assert!(matches!(result, Err(Error::RowMapping { .. })));
+
+    let results = TestResultMappingAdHoc::select_query::<JsonRow>(&[
+        &TestResultMappingAdHocColumn::NAME,
+        &TestResultMappingAdHocColumn::AMOUNT,
+    ]).order(&TestResultMappingAdHocColumn::NAME, OrderDirection::ASC).fetch(&conn).await.unwrap();
+    assert_eq!(results[0]["name"], serde_json::json!("a"));
+    assert_eq!(results[0]["amount"], serde_json::json!(5));
+    assert_eq!(results[1]["amount"], serde_json::Value::Null);
+
+    let results = TestResultMappingAdHoc::select_query::<DeserializedResult<TestReport>>(&[
+        &TestResultMappingAdHocColumn::NAME,
+        &TestResultMappingAdHocColumn::AMOUNT,
+    ]).order(&TestResultMappingAdHocColumn::NAME, OrderDirection::ASC).fetch(&conn).await.unwrap();
+    assert_eq!(results[0].name, "a");
+    assert_eq!(results[0].amount, Some(5));
+    assert_eq!(results[1].amount, None);
+
+    TestResultMappingAdHoc::drop_table(&conn).await.unwrap();
+}
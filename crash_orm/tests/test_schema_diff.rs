@@ -0,0 +1,37 @@
+use crash_orm::prelude::*;
+use crash_orm_test::setup_test_connection;
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemSchemaDiff {
+    pub id: u32,
+    pub name: String,
+    pub count: Option<i32>,
+}
+
+#[tokio::test]
+async fn test_schema_diff() {
+    let conn = setup_test_connection().await;
+    TestItemSchemaDiff::drop_table(&conn).await.unwrap();
+
+    let statements = TestItemSchemaDiff::diff_table(&conn).await.unwrap();
+    assert_eq!(statements.len(), 3);
+    assert!(statements[1].starts_with("CREATE TABLE public.test_item_schema_diff("));
+    assert!(!TestItemSchemaDiff::table_exists(&conn).await.unwrap());
+
+    TestItemSchemaDiff::create_table(&conn).await.unwrap();
+    assert!(TestItemSchemaDiff::diff_table(&conn).await.unwrap().is_empty());
+
+    conn.execute_query("ALTER TABLE test_item_schema_diff DROP COLUMN count, ADD COLUMN extra text, \
+        ALTER COLUMN name TYPE varchar, ALTER COLUMN name DROP NOT NULL", &[]).await.unwrap();
+
+    let statements = TestItemSchemaDiff::diff_table(&conn).await.unwrap();
+    assert_eq!(statements, vec![String::from("ALTER TABLE test_item_schema_diff DROP COLUMN extra,\
+        ALTER COLUMN name TYPE text,ALTER COLUMN name SET NOT NULL,ADD COLUMN count int4")]);
+
+    for statement in statements {
+        conn.execute_query(&statement, &[]).await.unwrap();
+    }
+    assert!(TestItemSchemaDiff::diff_table(&conn).await.unwrap().is_empty());
+
+    TestItemSchemaDiff::drop_table(&conn).await.unwrap();
+}
@@ -0,0 +1,73 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemSqlFunction {
+    id: u32,
+    name: String,
+    code: Option<String>,
+}
+
+sql_function!(left(String, i32) -> String);
+sql_function!(strpos(String, String) -> i32);
+sql_function!(pi() -> f64);
+sql_function!(text_length = "char_length"(String) -> i32);
+sql_function!(starts_with(String, String));
+sql_operator!(concat = "||"(String, String) -> String);
+sql_operator!(prefixed = "^@"(String, String));
+
+#[tokio::test]
+async fn test_sql_function() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemSqlFunction, conn);
+
+    vec![("apple", Some("A1")), ("banana", None), ("apricot", Some("A2"))]
+        .into_iter().map(|(name, code)| TestItemSqlFunctionCreate {
+            name: name.to_string(),
+            code: code.map(|v| v.to_string()),
+        }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    // Select
+    let results = TestItemSqlFunction::select_query::<(String, i32, i32, Option<String>)>(&[
+        &left(TestItemSqlFunctionColumn::NAME, 3),
+        &strpos(TestItemSqlFunctionColumn::NAME, "p"),
+        &text_length(TestItemSqlFunctionColumn::NAME),
+        &concat(TestItemSqlFunctionColumn::NAME, TestItemSqlFunctionColumn::CODE),
+    ])
+        .order(&TestItemSqlFunctionColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("app"), 2, 5, Some(String::from("appleA1"))),
+        (String::from("ban"), 0, 6, None),
+        (String::from("apr"), 2, 7, Some(String::from("apricotA2"))),
+    ]);
+
+    let result = TestItemSqlFunction::select_query::<SingleResult<f64>>(&[&pi::<TestItemSqlFunction>()])
+        .fetch_single(&conn).await.unwrap().unwrap();
+    assert_eq!(*result, std::f64::consts::PI);
+
+    // Conditions and order
+    let names = |results: Vec<TestItemSqlFunction>| results.into_iter().map(|v| v.name).collect::<Vec<_>>();
+    let results = TestItemSqlFunction::query()
+        .condition(starts_with(TestItemSqlFunctionColumn::NAME, "ap"))
+        .order(&text_length(TestItemSqlFunctionColumn::NAME), OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(names(results), vec!["apricot", "apple"]);
+
+    let results = TestItemSqlFunction::query()
+        .condition(prefixed(TestItemSqlFunctionColumn::NAME, "b")
+            .or(left(TestItemSqlFunctionColumn::NAME, 3).equals("apr")))
+        .order(&TestItemSqlFunctionColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(names(results), vec!["banana", "apricot"]);
+
+    // Update
+    TestItemSqlFunction::update_query()
+        .set(&TestItemSqlFunctionColumn::NAME, concat(left(TestItemSqlFunctionColumn::NAME, 2), "!"))
+        .condition(prefixed(TestItemSqlFunctionColumn::NAME, "a"))
+        .execute(&conn).await.unwrap();
+    let results = TestItemSqlFunction::query()
+        .order(&TestItemSqlFunctionColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(names(results), vec!["ap!", "banana", "ap!"]);
+}
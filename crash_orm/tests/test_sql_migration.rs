@@ -0,0 +1,43 @@
+use crash_orm::prelude::*;
+use crash_orm_test::setup_test_connection;
+
+struct SqlMigrationManager;
+
+impl CrashOrmMigrationManager for SqlMigrationManager {
+    fn get_migrations() -> Vec<Box<dyn Migration>> {
+        include_sql_migrations!("tests/sql_migrations")
+    }
+}
+
+#[test]
+fn test_sql_migration_load_dir() {
+    let migrations = SqlMigration::load_dir::<CrashOrmDatabaseConnection>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/sql_migrations")).unwrap();
+    let names = migrations.iter().map(|m| m.get_name()).collect::<Vec<&str>>();
+    assert_eq!(names, vec!["0001_create_test_sql_migration", "0002_insert_test_sql_migration"]);
+
+    let embedded = SqlMigrationManager::get_migrations();
+    let embedded_names = embedded.iter().map(|m| m.get_name()).collect::<Vec<&str>>();
+    assert_eq!(names, embedded_names);
+}
+
+#[tokio::test]
+async fn test_sql_migration() {
+    let conn = setup_test_connection().await;
+    TableDefinition::drop_table(&conn, "test_sql_migration").await.unwrap();
+    CrashOrmMigrationRecord::create_table_if_not_exists(&conn).await.unwrap();
+    CrashOrmMigrationRecord::delete()
+        .condition(CrashOrmMigrationRecordColumn::NAME.like("%_test_sql_migration"))
+        .execute(&conn).await.unwrap();
+
+    SqlMigrationManager::migrate_up(&conn).await.unwrap();
+    let rows = conn.query_many("SELECT name FROM test_sql_migration ORDER BY id", &[]).await.unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].get::<_, String>(0), "first");
+
+    SqlMigrationManager::migrate_down_prev(&conn).await.unwrap();
+    let rows = conn.query_many("SELECT name FROM test_sql_migration", &[]).await.unwrap();
+    assert!(rows.is_empty());
+
+    SqlMigrationManager::migrate_down_to(&conn, "0001_create_test_sql_migration").await.unwrap();
+    assert!(TableDefinition::load_from_database(&conn, "test_sql_migration").await.is_err());
+}
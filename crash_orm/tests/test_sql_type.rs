@@ -0,0 +1,215 @@
+use std::error::Error;
+
+use crash_orm::postgres::types::private::BytesMut;
+use crash_orm::postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TestPriority(i16);
+
+impl SqlType for TestPriority {
+    const SQL_TYPE: &'static str = "int2";
+}
+
+impl OrderedSqlType for TestPriority {}
+
+impl ToSql for TestPriority {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i16 as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for TestPriority {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> std::result::Result<Self, Box<dyn Error + Sync + Send>> {
+        i16::from_sql(ty, raw).map(TestPriority)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i16 as FromSql>::accepts(ty)
+    }
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemSqlType {
+    id: u32,
+    priority: TestPriority,
+    previous_priority: Option<TestPriority>,
+    #[sql_type = "varchar(8)"]
+    code: String,
+    #[sql_type = "varchar(8)"]
+    optional_code: Option<String>,
+}
+
+#[tokio::test]
+async fn test_sql_type() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemSqlType, conn);
+
+    let columns = conn.query_many(
+        "SELECT column_name::text, data_type::text, is_nullable::text FROM information_schema.columns \
+        WHERE table_name = 'test_item_sql_type' ORDER BY ordinal_position",
+        &[],
+    ).await.unwrap().iter().map(|row| (row.get(0), row.get(1), row.get(2))).collect::<Vec<(String, String, String)>>();
+    let columns = columns.iter().map(|(a, b, c)| (a.as_str(), b.as_str(), c.as_str())).collect::<Vec<_>>();
+    assert_eq!(columns, vec![
+        ("id", "oid", "NO"),
+        ("priority", "smallint", "NO"),
+        ("previous_priority", "smallint", "YES"),
+        ("code", "character varying", "NO"),
+        ("optional_code", "character varying", "YES"),
+    ]);
+
+    vec![
+        TestItemSqlTypeCreate {
+            priority: TestPriority(2),
+            previous_priority: None,
+            code: String::from("a"),
+            optional_code: None,
+        },
+        TestItemSqlTypeCreate {
+            priority: TestPriority(7),
+            previous_priority: Some(TestPriority(2)),
+            code: String::from("b"),
+            optional_code: Some(String::from("c")),
+        },
+    ].insert_all(&conn).await.unwrap();
+
+    let results = TestItemSqlType::query()
+        .condition(TestItemSqlTypeColumn::PRIORITY.equals(TestPriority(7)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].previous_priority, Some(TestPriority(2)));
+
+    let results = TestItemSqlType::query()
+        .condition(TestItemSqlTypeColumn::PRIORITY.in_vec(vec![TestPriority(2), TestPriority(3)]))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].code, "a");
+
+    let results = TestItemSqlType::query()
+        .condition(TestItemSqlTypeColumn::PRIORITY.greater_than(TestPriority(2)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].optional_code.as_deref(), Some("c"));
+
+    let results = TestItemSqlType::query()
+        .condition(TestItemSqlTypeColumn::PREVIOUS_PRIORITY.less_equal(TestPriority(2)))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[derive(Entity, Debug, Schema)]
+#[exclude(name = "\"test_item_{braces}_excl\"", using = "btree", columns(priority = "="))]
+pub struct TestItemSqlTypeBraces {
+    id: u32,
+    priority: TestPriority,
+    #[generated = "'{' || priority::text || '}'"]
+    label: String,
+}
+
+#[tokio::test]
+async fn test_sql_type_braces() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemSqlTypeBraces, conn);
+
+    let item = TestItemSqlTypeBracesCreate {
+        priority: TestPriority(3),
+    }.insert(&conn).await.unwrap();
+    let item = TestItemSqlTypeBraces::get_by_primary(&conn, item.id).await.unwrap().unwrap();
+    assert_eq!(item.label, "{3}");
+
+    let result = TestItemSqlTypeBracesCreate {
+        priority: TestPriority(3),
+    }.insert(&conn).await;
+    assert!(matches!(result, Err(crash_orm::Error::ExclusionViolation { constraint: Some(ref name), .. }) if name == "test_item_{braces}_excl"));
+}
+
+mod custom {
+    use std::error::Error;
+
+    use crash_orm::postgres::types::private::BytesMut;
+    use crash_orm::postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use crash_orm::prelude::SqlType;
+
+    /// Named like the date of chrono and time, but stored as text
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Date(pub String);
+
+    impl SqlType for Date {
+        const SQL_TYPE: &'static str = "varchar(10)";
+    }
+
+    impl ToSql for Date {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+            self.0.to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <String as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+
+    impl<'a> FromSql<'a> for Date {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> std::result::Result<Self, Box<dyn Error + Sync + Send>> {
+            String::from_sql(ty, raw).map(Date)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <String as FromSql>::accepts(ty)
+        }
+    }
+}
+
+#[derive(DbEnum, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum TestSqlTypeLevel {
+    Low,
+    High,
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemSqlTypeNames {
+    id: u32,
+    date: custom::Date,
+    dates: Option<Vec<custom::Date>>,
+    #[sql_type = "test_sql_type_level"]
+    level: TestSqlTypeLevel,
+}
+
+#[tokio::test]
+async fn test_sql_type_names() {
+    let conn = setup_test_connection().await;
+    TestItemSqlTypeNames::drop_table(&conn).await.unwrap();
+    conn.execute_batch("DROP TYPE IF EXISTS test_sql_type_level").await.unwrap();
+    TestItemSqlTypeNames::create_table(&conn).await.unwrap();
+
+    let columns = conn.query_many(
+        "SELECT column_name::text, udt_name::text FROM information_schema.columns \
+        WHERE table_name = 'test_item_sql_type_names' ORDER BY ordinal_position",
+        &[],
+    ).await.unwrap().iter().map(|row| (row.get(0), row.get(1))).collect::<Vec<(String, String)>>();
+    let columns = columns.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect::<Vec<_>>();
+    assert_eq!(columns, vec![
+        ("id", "oid"),
+        ("date", "varchar"),
+        ("dates", "_varchar"),
+        ("level", "test_sql_type_level"),
+    ]);
+
+    let item = TestItemSqlTypeNamesCreate {
+        date: custom::Date(String::from("2024-01-01")),
+        dates: None,
+        level: TestSqlTypeLevel::High,
+    }.insert(&conn).await.unwrap();
+    let item = TestItemSqlTypeNames::get_by_primary(&conn, item.id).await.unwrap().unwrap();
+    assert_eq!(item.date, custom::Date(String::from("2024-01-01")));
+    assert_eq!(item.level, TestSqlTypeLevel::High);
+}
@@ -0,0 +1,131 @@
+use crash_orm::prelude::*;
+use crash_orm_test::setup_test_connection;
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestSubqueryCustomer {
+    pub id: u32,
+    pub name: String,
+    #[mapped_by("customer")]
+    pub invoices: OneToMany<TestSubqueryInvoice, u32>,
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestSubqueryInvoice {
+    pub id: u32,
+    pub customer: ManyToOne<TestSubqueryCustomer, u32>,
+    pub customer_name: String,
+    pub amount: i32,
+    pub paid: bool,
+}
+
+#[tokio::test]
+async fn test_subquery() {
+    let conn = setup_test_connection().await;
+
+    if TestSubqueryInvoice::table_exists(&conn).await.unwrap() {
+        TestSubqueryInvoice::drop_table(&conn).await.unwrap();
+    }
+    if TestSubqueryCustomer::table_exists(&conn).await.unwrap() {
+        TestSubqueryCustomer::drop_table(&conn).await.unwrap();
+    }
+    TestSubqueryCustomer::create_table(&conn).await.unwrap();
+    TestSubqueryInvoice::create_table(&conn).await.unwrap();
+
+    vec![
+        TestSubqueryCustomerCreate { name: String::from("Alice") },
+        TestSubqueryCustomerCreate { name: String::from("Bob") },
+        TestSubqueryCustomerCreate { name: String::from("Carol") },
+    ].insert_all(&conn).await.unwrap();
+
+    vec![
+        TestSubqueryInvoiceCreate { customer: ManyToOne::new(1), customer_name: String::from("Alice"), amount: 100, paid: true },
+        TestSubqueryInvoiceCreate { customer: ManyToOne::new(1), customer_name: String::from("Alice"), amount: 50, paid: false },
+        TestSubqueryInvoiceCreate { customer: ManyToOne::new(2), customer_name: String::from("Bob"), amount: 70, paid: true },
+    ].insert_all(&conn).await.unwrap();
+
+    // Customers with at least one unpaid invoice
+    let results = TestSubqueryCustomer::query()
+        .condition(QueryCondition::exists(
+            TestSubqueryInvoice::query()
+                .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryCustomerColumn::ID.outer())
+                    .and(TestSubqueryInvoiceColumn::PAID.equals(false)))
+        ))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "Alice");
+
+    // Customers without invoices
+    let results = TestSubqueryCustomer::query()
+        .condition(QueryCondition::not_exists(
+            TestSubqueryInvoice::query()
+                .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryCustomerColumn::ID.outer()))
+        ))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "Carol");
+
+    // Parameters of the outer query and of the subquery are numbered in order
+    let results = TestSubqueryCustomer::query()
+        .condition(TestSubqueryCustomerColumn::NAME.not_equals("Carol").and(TestSubqueryCustomerColumn::NAME.in_query(
+            TestSubqueryInvoice::select_query::<SingleResult<String>>(&[&TestSubqueryInvoiceColumn::CUSTOMER_NAME])
+                .condition(TestSubqueryInvoiceColumn::AMOUNT.greater_than(60))
+        )))
+        .order(&TestSubqueryCustomerColumn::NAME, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| &*v.name).collect::<Vec<_>>(), vec!["Alice", "Bob"]);
+
+    let results = TestSubqueryCustomer::query()
+        .condition(TestSubqueryCustomerColumn::NAME.not_in_query(
+            TestSubqueryInvoice::select_query::<SingleResult<String>>(&[&TestSubqueryInvoiceColumn::CUSTOMER_NAME])
+        ))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "Carol");
+
+    // Scalar subquery as column
+    let invoice_count = VirtualColumn::subquery(
+        TestSubqueryInvoice::select_query::<SingleResult<i64>>(&[&TestSubqueryInvoiceColumn::ID.count_column(false)])
+            .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryCustomerColumn::ID.outer()))
+    );
+    let results = TestSubqueryCustomer::select_query::<(String, i64)>(&[&TestSubqueryCustomerColumn::NAME, &invoice_count])
+        .condition(invoice_count.greater_than(0))
+        .order(&invoice_count, OrderDirection::DESC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("Alice"), 2), (String::from("Bob"), 1)]);
+
+    let open_amount = VirtualColumn::subquery(
+        TestSubqueryInvoice::select_query::<SingleResult<i64>>(&[&TestSubqueryInvoiceColumn::AMOUNT.sum(false)])
+            .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryCustomerColumn::ID.outer())
+                .and(TestSubqueryInvoiceColumn::PAID.equals(false)))
+    );
+    let results = TestSubqueryCustomer::select_query::<(String, Option<i64>)>(&[&TestSubqueryCustomerColumn::NAME, &open_amount])
+        .order(&TestSubqueryCustomerColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("Alice"), Some(50)), (String::from("Bob"), None), (String::from("Carol"), None)]);
+
+    // Correlated subquery of the same entity, the table of the subquery is aliased
+    let results = TestSubqueryInvoice::query()
+        .condition(QueryCondition::exists(
+            TestSubqueryInvoice::query()
+                .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.outer())
+                    .and(TestSubqueryInvoiceColumn::AMOUNT.greater_than(TestSubqueryInvoiceColumn::AMOUNT.outer())))
+        ))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].amount, 50);
+
+    // The union of the subquery is aliased like the table
+    let results = TestSubqueryInvoice::query()
+        .condition(QueryCondition::exists(
+            TestSubqueryInvoice::query().condition(TestSubqueryInvoiceColumn::PAID.equals(true))
+                .union(TestSubqueryInvoice::query().condition(TestSubqueryInvoiceColumn::PAID.equals(false)))
+                .condition(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.equals(TestSubqueryInvoiceColumn::CUSTOMER_PRIMARY.outer())
+                    .and(TestSubqueryInvoiceColumn::AMOUNT.greater_than(TestSubqueryInvoiceColumn::AMOUNT.outer())))
+        ))
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].amount, 50);
+
+    TestSubqueryInvoice::drop_table(&conn).await.unwrap();
+    TestSubqueryCustomer::drop_table(&conn).await.unwrap();
+}
@@ -0,0 +1,87 @@
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemUnionPost {
+    id: u32,
+    title: String,
+    created: i32,
+}
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemUnionComment {
+    id: u32,
+    text: String,
+    created: i32,
+}
+
+#[tokio::test]
+async fn test_union() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemUnionPost, conn);
+    default_create_table!(TestItemUnionComment, conn);
+
+    vec![("first post", 1), ("second post", 4), ("same", 6)].into_iter().map(|(title, created)| TestItemUnionPostCreate {
+        title: title.to_string(),
+        created,
+    }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+    vec![("nice", 2), ("spam", 3), ("great", 5), ("same", 6)].into_iter().map(|(text, created)| TestItemUnionCommentCreate {
+        text: text.to_string(),
+        created,
+    }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    let posts = || TestItemUnionPost::select_query::<(String, i32)>(&[&TestItemUnionPostColumn::TITLE, &TestItemUnionPostColumn::CREATED]);
+    let comments = || TestItemUnionComment::select_query::<(String, i32)>(&[&TestItemUnionCommentColumn::TEXT, &TestItemUnionCommentColumn::CREATED]);
+
+    // Timeline with parameters on both sides, ordered and limited as a whole
+    let results = posts()
+        .condition(TestItemUnionPostColumn::CREATED.greater_than(1))
+        .union_all(comments().condition(TestItemUnionCommentColumn::TEXT.not_equals("spam")))
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::DESC)
+        .limit(4)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("same"), 6),
+        (String::from("same"), 6),
+        (String::from("great"), 5),
+        (String::from("second post"), 4),
+    ]);
+
+    let results = posts()
+        .union(comments())
+        .condition(TestItemUnionPostColumn::CREATED.greater_equal(5))
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("great"), 5), (String::from("same"), 6)]);
+
+    let results = posts().intersect(comments()).fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("same"), 6)]);
+
+    let results = posts()
+        .except(comments())
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::ASC)
+        .offset(1)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![(String::from("second post"), 4)]);
+
+    // Order and limit of a side only apply to its own rows
+    let results = posts()
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::ASC)
+        .limit(1)
+        .union_all(comments().condition(TestItemUnionCommentColumn::CREATED.less_than(3)))
+        .union_all(posts().condition(TestItemUnionPostColumn::TITLE.equals("same")))
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (String::from("first post"), 1),
+        (String::from("nice"), 2),
+        (String::from("same"), 6),
+    ]);
+
+    let result = posts()
+        .union_all(comments())
+        .order(&TestItemUnionPostColumn::CREATED, OrderDirection::DESC)
+        .add_order(&TestItemUnionPostColumn::TITLE, OrderDirection::ASC)
+        .fetch_single(&conn).await.unwrap();
+    assert_eq!(result, Some((String::from("same"), 6)));
+}
@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+
+use crash_orm::prelude::*;
+use crash_orm_test::{default_create_table, setup_test_connection};
+
+#[derive(Entity, Debug, Schema)]
+pub struct TestItemWindow {
+    id: u32,
+    game: String,
+    player: String,
+    score: i32,
+}
+
+#[tokio::test]
+async fn test_window() {
+    let conn = setup_test_connection().await;
+    default_create_table!(TestItemWindow, conn);
+
+    vec![
+        ("chess", "alice", 30),
+        ("chess", "bob", 50),
+        ("chess", "carol", 30),
+        ("chess", "dave", 10),
+        ("go", "alice", 20),
+        ("go", "erin", 40),
+    ].into_iter().map(|(game, player, score)| TestItemWindowCreate {
+        game: game.to_string(),
+        player: player.to_string(),
+        score,
+    }).collect::<Vec<_>>().insert_all(&conn).await.unwrap();
+
+    // Leaderboard per game
+    let leaderboard = Window::new()
+        .partition_by(&TestItemWindowColumn::GAME)
+        .order_by(&TestItemWindowColumn::SCORE, OrderDirection::DESC);
+    let results = TestItemWindow::select_query::<(String, i64, i64, i64)>(&[
+        &TestItemWindowColumn::PLAYER,
+        &leaderboard.row_number(),
+        &leaderboard.rank(),
+        &leaderboard.dense_rank(),
+    ])
+        .condition(TestItemWindowColumn::GAME.equals("chess"))
+        .order(&TestItemWindowColumn::SCORE, OrderDirection::DESC)
+        .add_order(&TestItemWindowColumn::PLAYER, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| (v.2, v.3)).collect::<Vec<_>>(), vec![(1, 1), (2, 2), (2, 2), (4, 3)]);
+    assert_eq!(results[0].0, "bob");
+    assert_eq!(results.iter().map(|v| v.1).max(), Some(4));
+
+    // Previous and next score by id
+    let by_id = Window::new().order_by(&TestItemWindowColumn::ID, OrderDirection::ASC);
+    let previous: VirtualColumn<Option<i32>, TestItemWindow> = TestItemWindowColumn::SCORE.lag(1, &by_id);
+    let results = TestItemWindow::select_query::<(Option<i32>, Option<i32>, i32)>(&[
+        &previous,
+        &TestItemWindowColumn::SCORE.lead(2, &by_id),
+        &TestItemWindowColumn::SCORE.first_value(&Window::new().partition_by(&TestItemWindowColumn::GAME).order_by(&TestItemWindowColumn::ID, OrderDirection::ASC)),
+    ])
+        .order(&TestItemWindowColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results, vec![
+        (None, Some(30), 30),
+        (Some(30), Some(10), 30),
+        (Some(50), Some(20), 30),
+        (Some(30), Some(40), 30),
+        (Some(10), None, 20),
+        (Some(20), None, 20),
+    ]);
+
+    // Running total and moving average
+    let running = Window::new()
+        .partition_by(&TestItemWindowColumn::GAME)
+        .order_by(&TestItemWindowColumn::ID, OrderDirection::ASC)
+        .rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+    let moving = Window::new()
+        .order_by(&TestItemWindowColumn::ID, OrderDirection::ASC)
+        .rows(FrameBound::Preceding(1), FrameBound::Following(1));
+    let results = TestItemWindow::select_query::<(i64, Decimal, i32)>(&[
+        &TestItemWindowColumn::SCORE.sum(false).over(&running),
+        &TestItemWindowColumn::SCORE.avg(false).over(&moving),
+        &TestItemWindowColumn::SCORE.last_value(&Window::new().order_by(&TestItemWindowColumn::ID, OrderDirection::ASC)
+            .range(FrameBound::UnboundedPreceding, FrameBound::UnboundedFollowing)),
+    ])
+        .order(&TestItemWindowColumn::ID, OrderDirection::ASC)
+        .fetch(&conn).await.unwrap();
+    assert_eq!(results.iter().map(|v| v.0).collect::<Vec<_>>(), vec![30, 80, 110, 120, 20, 60]);
+    assert_eq!(results[0].1, Decimal::from(40));
+    assert_eq!(results[5].1, Decimal::from(30));
+    assert!(results.iter().all(|v| v.2 == 40));
+
+    TestItemWindow::drop_table(&conn).await.unwrap();
+}
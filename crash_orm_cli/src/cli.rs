@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+/// Command line arguments of the migration CLI.
+#[derive(Parser, Debug)]
+#[command(about = "Manage the migrations of your Crash ORM database")]
+pub struct Cli {
+    /// URL of the database, defaults to the environment variable `DATABASE_URL`
+    #[arg(long, global = true)]
+    pub database_url: Option<String>,
+    /// Command to execute
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Top level commands of the migration CLI.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage migrations
+    #[command(subcommand)]
+    Migrate(MigrateCommand),
+    /// Inspect the database schema
+    #[command(subcommand)]
+    Schema(SchemaCommand),
+}
+
+/// Subcommands of `migrate`.
+#[derive(Subcommand, Debug)]
+pub enum MigrateCommand {
+    /// Execute all pending migrations
+    Up {
+        /// Print the SQL instead of executing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Revert migrations, by default only the latest migration
+    Down {
+        /// Revert all migrations down to and including this migration
+        #[arg(long)]
+        to: Option<String>,
+        /// Print the SQL instead of executing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List all migrations and whether they have been executed
+    Status,
+    /// Create a new SQL migration
+    New {
+        /// Name of the migration, prefixed with the next migration number
+        name: String,
+        /// Directory of the SQL migrations
+        #[arg(long, default_value = "migrations")]
+        dir: PathBuf,
+    },
+}
+
+/// Subcommands of `schema`.
+#[derive(Subcommand, Debug)]
+pub enum SchemaCommand {
+    /// Print the SQL changing the database to match the entities of the migration manager
+    Diff,
+}
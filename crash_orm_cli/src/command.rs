@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use crash_orm::postgres::NoTls;
+use crash_orm::prelude::*;
+
+use crate::cli::{Cli, Command, MigrateCommand, SchemaCommand};
+
+impl Cli {
+    /// Executes the command with the migration manager `M` and returns the output.
+    ///
+    /// Connects to [database_url](Cli::database_url) or, if not set, the environment variable `DATABASE_URL`.
+    /// `migrate new` does not require a database connection.
+    pub async fn run<M: CrashOrmMigrationManager>(self) -> Result<String> {
+        if let Command::Migrate(MigrateCommand::New { name, dir }) = &self.command {
+            return new_migration(dir, name);
+        }
+
+        let url = match self.database_url {
+            Some(url) => url,
+            None => std::env::var("DATABASE_URL")
+                .map_err(|_| Error::from_str("No database url provided and DATABASE_URL is not set"))?,
+        };
+
+        let conn = CrashOrmDatabaseConnection::new(&url, NoTls).await?;
+        self.command.execute::<M>(&conn).await
+    }
+}
+
+impl Command {
+    /// Executes this command with the migration manager `M` on the connection and returns the output.
+    pub async fn execute<M: CrashOrmMigrationManager>(self, conn: &CrashOrmDatabaseConnection) -> Result<String> {
+        match self {
+            Command::Migrate(MigrateCommand::Up { dry_run: true }) => M::dry_run(conn, MigrationAction::Up).await,
+            Command::Migrate(MigrateCommand::Up { dry_run: false }) => {
+                let pending = M::pending_migrations(conn).await?;
+                M::migrate_up(conn).await?;
+
+                if pending.is_empty() {
+                    return Ok(String::from("No pending migrations\n"));
+                }
+
+                Ok(pending.iter().map(|m| format!("Executed {}\n", m.get_name())).collect())
+            }
+            Command::Migrate(MigrateCommand::Down { to, dry_run }) => {
+                let action = match to {
+                    Some(name) => {
+                        if !M::get_migrations().iter().any(|m| m.get_name() == name) {
+                            return Err(Error::String(format!("Migration {} not found in local migrations", name)));
+                        }
+
+                        MigrationAction::DownTo(name)
+                    }
+                    None => MigrationAction::DownPrev,
+                };
+
+                if dry_run {
+                    return M::dry_run(conn, action).await;
+                }
+
+                let reverted = match action {
+                    MigrationAction::DownTo(name) => {
+                        let reverted = M::migrations_down_to(conn, &name).await?;
+                        M::migrate_down_to(conn, &name).await?;
+                        reverted
+                    }
+                    _ => {
+                        let reverted = M::migration_down_prev(conn).await?;
+                        M::migrate_down_prev(conn).await?;
+                        vec![reverted]
+                    }
+                };
+
+                Ok(reverted.iter().map(|m| format!("Reverted {}\n", m.get_name())).collect())
+            }
+            Command::Migrate(MigrateCommand::Status) => migration_status::<M>(conn).await,
+            Command::Migrate(MigrateCommand::New { name, dir }) => new_migration(&dir, &name),
+            Command::Schema(SchemaCommand::Diff) => {
+                let script = M::schema_diff(conn).await?;
+
+                if script.is_empty() {
+                    return Ok(String::from("Schema is up to date\n"));
+                }
+
+                Ok(script)
+            }
+        }
+    }
+}
+
+/// Lists all local migrations with their execution time, followed by executed migrations missing locally.
+async fn migration_status<M: CrashOrmMigrationManager>(conn: &CrashOrmDatabaseConnection) -> Result<String> {
+    let records = if CrashOrmMigrationRecord::table_exists(conn).await? {
+        CrashOrmMigrationRecord::query()
+            .order(&CrashOrmMigrationRecordColumn::ID, OrderDirection::ASC)
+            .fetch(conn).await?
+    } else {
+        vec![]
+    };
+
+    let migrations = M::get_migrations();
+    let mut output = String::new();
+
+    for migration in &migrations {
+        match records.iter().find(|record| record.name == migration.get_name()) {
+            Some(record) => output.push_str(&format!("[x] {} (executed at {})\n", migration.get_name(), record.executed_at)),
+            None => output.push_str(&format!("[ ] {}\n", migration.get_name())),
+        }
+    }
+
+    for record in &records {
+        if !migrations.iter().any(|m| m.get_name() == record.name) {
+            output.push_str(&format!("[?] {} (executed at {}, not found in local migrations)\n", record.name, record.executed_at));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Creates empty up and down files for a new [SqlMigration] numbered after the existing migrations in `dir`.
+fn new_migration(dir: &Path, name: &str) -> Result<String> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(Error::String(format!("Invalid migration name {}, only alphanumeric characters and _ are allowed", name)));
+    }
+
+    std::fs::create_dir_all(dir)
+        .map_err(|e| Error::String(format!("Failed to create migration directory {}: {}", dir.display(), e)))?;
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::String(format!("Failed to read migration directory {}: {}", dir.display(), e)))?;
+
+    let mut latest = 0;
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::String(format!("Failed to read migration directory {}: {}", dir.display(), e)))?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        if !file_name.ends_with(".up.sql") && !file_name.ends_with(".down.sql") {
+            continue;
+        }
+
+        if let Some(number) = file_name.split_once('_').and_then(|(number, _)| number.parse::<u64>().ok()) {
+            latest = latest.max(number);
+        }
+    }
+
+    let name = format!("{:04}_{}", latest + 1, name);
+    let mut output = String::new();
+    for (suffix, direction) in [("up", "Up"), ("down", "Down")] {
+        let path = dir.join(format!("{}.{}.sql", name, suffix));
+        std::fs::write(&path, format!("-- {} migration of {}\n", direction, name))
+            .map_err(|e| Error::String(format!("Failed to write migration file {}: {}", path.display(), e)))?;
+        output.push_str(&format!("Created {}\n", path.display()));
+    }
+
+    Ok(output)
+}
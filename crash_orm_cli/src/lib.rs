@@ -0,0 +1,65 @@
+//! Command line interface to manage the migrations of crash_orm.
+//!
+//! Add a binary to your migration crate and pass your [CrashOrmMigrationManager] to [main]:
+//!
+//! ```no_run
+//! use crash_orm::prelude::*;
+//!
+//! pub struct MigrationManager;
+//!
+//! impl CrashOrmMigrationManager for MigrationManager {
+//!     fn get_migrations() -> Vec<Box<dyn Migration>> {
+//!         vec![
+//!
+//!         ]
+//!     }
+//! }
+//!
+//! fn main() {
+//!     crash_orm_cli::main::<MigrationManager>();
+//! }
+//! ```
+//!
+//! The binary connects to the database from the environment variable `DATABASE_URL`,
+//! which can be overwritten with `--database-url`.
+//!
+//! ## Commands
+//! - `migrate up [--dry-run]`: execute all pending migrations
+//! - `migrate down [--to <name>] [--dry-run]`: revert the latest migration or all migrations down to and including `<name>`
+//! - `migrate status`: list all migrations and whether they have been executed
+//! - `migrate new <name> [--dir <dir>]`: create the files of a new [SqlMigration](crash_orm::migration::SqlMigration) in `<dir>`, default `migrations`
+//! - `schema diff`: print the SQL changing the tables in the database to match the entities of the migration manager
+//!
+//! The dry runs use [dry_run](CrashOrmMigrationManager::dry_run).
+//! To review the SQL of all pending migrations before deploying them, run `migrate up --dry-run`.
+//!
+//! `schema diff` uses [schema_diff](CrashOrmMigrationManager::schema_diff) and compares the entities returned by
+//! [get_entities](CrashOrmMigrationManager::get_entities) with the database, e.g. to write the migration of a changed entity.
+
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![warn(missing_docs)]
+
+mod cli;
+mod command;
+
+pub use cli::*;
+
+use clap::Parser;
+use crash_orm::migration::CrashOrmMigrationManager;
+
+/// Runs the CLI with the arguments of the process and your migration manager `M`.
+///
+/// Prints the output of the command, or the error and exits with code 1.
+pub fn main<M: CrashOrmMigrationManager>() {
+    let cli = Cli::parse();
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to start tokio runtime");
+    match runtime.block_on(cli.run::<M>()) {
+        Ok(output) => print!("{}", output),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
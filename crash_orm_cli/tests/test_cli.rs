@@ -0,0 +1,118 @@
+use clap::Parser;
+use crash_orm::prelude::*;
+use crash_orm_cli::{Cli, Command, MigrateCommand, SchemaCommand};
+use crash_orm_test::setup_test_connection;
+
+const FIRST_MIGRATION: &str = "test_cli_0001";
+const SECOND_MIGRATION: &str = "test_cli_0002";
+
+/// Only describes the table, whose columns are compared by `schema diff`
+#[allow(dead_code)]
+#[derive(Debug, Schema)]
+struct TestCli {
+    id: i32,
+    name: Option<String>,
+}
+
+struct MigrationManager;
+
+impl CrashOrmMigrationManager for MigrationManager {
+    fn get_migrations() -> Vec<Box<dyn Migration>> {
+        vec![
+            Box::new(SqlMigration::new(FIRST_MIGRATION, "CREATE TABLE test_cli(id int4 PRIMARY KEY)")
+                .with_down("DROP TABLE test_cli")),
+            Box::new(SqlMigration::new(SECOND_MIGRATION, "ALTER TABLE test_cli ADD COLUMN name text")
+                .with_down("ALTER TABLE test_cli DROP COLUMN name")),
+        ]
+    }
+
+    fn get_entities() -> Vec<EntitySchema> {
+        vec![EntitySchema::of::<TestCli>()]
+    }
+}
+
+#[test]
+fn test_parse() {
+    let cli = Cli::try_parse_from(["crash_orm_cli", "migrate", "down", "--to", FIRST_MIGRATION, "--dry-run"]).unwrap();
+    assert!(cli.database_url.is_none());
+    assert!(matches!(cli.command, Command::Migrate(MigrateCommand::Down { to: Some(ref to), dry_run: true }) if to == FIRST_MIGRATION));
+
+    let cli = Cli::try_parse_from(["crash_orm_cli", "migrate", "status", "--database-url", "postgresql://localhost/test"]).unwrap();
+    assert_eq!(cli.database_url.as_deref(), Some("postgresql://localhost/test"));
+    assert!(matches!(cli.command, Command::Migrate(MigrateCommand::Status)));
+
+    assert!(Cli::try_parse_from(["crash_orm_cli", "migrate", "new"]).is_err());
+
+    let cli = Cli::try_parse_from(["crash_orm_cli", "schema", "diff"]).unwrap();
+    assert!(matches!(cli.command, Command::Schema(SchemaCommand::Diff)));
+}
+
+#[tokio::test]
+async fn test_migrate_new() {
+    let dir = std::env::temp_dir().join("crash_orm_test_cli_new");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let cli = Cli::try_parse_from(["crash_orm_cli", "migrate", "new", "create_users", "--dir", dir.to_str().unwrap()]).unwrap();
+    let output = cli.run::<MigrationManager>().await.unwrap();
+    assert!(output.contains("0001_create_users.up.sql"));
+    assert!(dir.join("0001_create_users.down.sql").exists());
+
+    let cli = Cli::try_parse_from(["crash_orm_cli", "migrate", "new", "add_email", "--dir", dir.to_str().unwrap()]).unwrap();
+    cli.run::<MigrationManager>().await.unwrap();
+    assert!(dir.join("0002_add_email.up.sql").exists());
+
+    let migrations = SqlMigration::load_dir::<CrashOrmDatabaseConnection>(&dir).unwrap();
+    assert_eq!(migrations.len(), 2);
+
+    let cli = Cli::try_parse_from(["crash_orm_cli", "migrate", "new", "add email", "--dir", dir.to_str().unwrap()]).unwrap();
+    assert!(cli.run::<MigrationManager>().await.is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate() {
+    let conn = setup_test_connection().await;
+    CrashOrmMigrationRecord::create_table_if_not_exists(&conn).await.unwrap();
+    CrashOrmMigrationRecord::delete()
+        .condition(CrashOrmMigrationRecordColumn::NAME.like("test_cli_%"))
+        .execute(&conn).await.unwrap();
+    conn.execute_batch("DROP TABLE IF EXISTS test_cli").await.unwrap();
+
+    let execute = async |args: &[&str]| {
+        let cli = Cli::try_parse_from([&["crash_orm_cli"], args].concat()).unwrap();
+        cli.command.execute::<MigrationManager>(&conn).await
+    };
+
+    let status = execute(&["migrate", "status"]).await.unwrap();
+    assert_eq!(status, format!("[ ] {}\n[ ] {}\n", FIRST_MIGRATION, SECOND_MIGRATION));
+
+    let diff = execute(&["schema", "diff"]).await.unwrap();
+    assert!(diff.starts_with("CREATE SEQUENCE test_cli_id_seq;\nCREATE TABLE public.test_cli(id int4 NOT NULL DEFAULT nextval('test_cli_id_seq'::regclass),name text NULL,PRIMARY KEY (id));\n"));
+
+    let script = execute(&["migrate", "up", "--dry-run"]).await.unwrap();
+    assert_eq!(script, format!("-- up: {}\nCREATE TABLE test_cli(id int4 PRIMARY KEY);\n-- up: {}\nALTER TABLE test_cli ADD COLUMN name text;\n", FIRST_MIGRATION, SECOND_MIGRATION));
+
+    let output = execute(&["migrate", "up"]).await.unwrap();
+    assert_eq!(output, format!("Executed {}\nExecuted {}\n", FIRST_MIGRATION, SECOND_MIGRATION));
+    assert_eq!(execute(&["migrate", "up"]).await.unwrap(), "No pending migrations\n");
+    assert_eq!(execute(&["migrate", "up", "--dry-run"]).await.unwrap(), "");
+    assert_eq!(execute(&["schema", "diff"]).await.unwrap(), "Schema is up to date\n");
+
+    let status = execute(&["migrate", "status"]).await.unwrap();
+    assert!(status.starts_with(&format!("[x] {} (executed at ", FIRST_MIGRATION)));
+    assert!(status.contains(&format!("[x] {} (executed at ", SECOND_MIGRATION)));
+
+    assert!(execute(&["migrate", "down", "--to", "unknown"]).await.is_err());
+
+    let output = execute(&["migrate", "down"]).await.unwrap();
+    assert_eq!(output, format!("Reverted {}\n", SECOND_MIGRATION));
+    assert_eq!(execute(&["schema", "diff"]).await.unwrap(), "ALTER TABLE test_cli ADD COLUMN name text;\n");
+
+    let output = execute(&["migrate", "down", "--to", FIRST_MIGRATION, "--dry-run"]).await.unwrap();
+    assert_eq!(output, format!("-- down: {}\nDROP TABLE test_cli;\n", FIRST_MIGRATION));
+
+    let output = execute(&["migrate", "down", "--to", FIRST_MIGRATION]).await.unwrap();
+    assert_eq!(output, format!("Reverted {}\n", FIRST_MIGRATION));
+    assert!(TableDefinition::load_from_database(&conn, "test_cli").await.is_err());
+}
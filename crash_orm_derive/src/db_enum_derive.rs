@@ -0,0 +1,114 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr};
+
+use crate::util::{ident_to_table_name, string_to_table_name};
+
+pub fn derive_db_enum_impl(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let Data::Enum(enum_data) = derive_input.data else {
+        panic!("only enums are supported")
+    };
+
+    let ident = derive_input.ident;
+    let type_name = get_db_enum_name(&derive_input.attrs).unwrap_or_else(|| ident_to_table_name(&ident));
+
+    let mut labels = vec![];
+    let mut to_label = quote!();
+    let mut from_label = quote!();
+
+    for variant in enum_data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            panic!("DbEnum only supports variants without fields");
+        }
+
+        let variant_ident = variant.ident;
+        let label = get_db_enum_name(&variant.attrs).unwrap_or_else(|| string_to_table_name(variant_ident.to_string()));
+
+        to_label.extend(quote! {
+            #ident::#variant_ident => #label,
+        });
+        from_label.extend(quote! {
+            #label => Some(#ident::#variant_ident),
+        });
+        labels.push(label);
+    }
+
+    let output = quote! {
+        #[crash_orm::async_trait::async_trait]
+        impl crash_orm::prelude::SqlType for #ident {
+            const SQL_TYPE: &'static str = #type_name;
+
+            async fn before_create_table(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<()> {
+                <Self as crash_orm::prelude::DbEnum>::create_type_if_not_exists(connection).await
+            }
+        }
+
+        impl crash_orm::prelude::OrderedSqlType for #ident {}
+
+        impl crash_orm::prelude::DbEnum for #ident {
+            const VARIANTS: &'static [&'static str] = &[#(#labels),*];
+
+            fn to_label(&self) -> &'static str {
+                match self {
+                    #to_label
+                }
+            }
+
+            fn from_label(label: &str) -> Option<Self> {
+                match label {
+                    #from_label
+                    _ => None,
+                }
+            }
+        }
+
+        impl crash_orm::postgres::types::ToSql for #ident {
+            fn to_sql(
+                &self,
+                _ty: &crash_orm::postgres::types::Type,
+                out: &mut crash_orm::postgres::types::private::BytesMut,
+            ) -> std::result::Result<crash_orm::postgres::types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                crash_orm::db_enum::db_enum_to_sql(self, out)
+            }
+
+            fn accepts(ty: &crash_orm::postgres::types::Type) -> bool {
+                crash_orm::db_enum::db_enum_accepts::<#ident>(ty)
+            }
+
+            crash_orm::postgres::types::to_sql_checked!();
+        }
+
+        impl<'a> crash_orm::postgres::types::FromSql<'a> for #ident {
+            fn from_sql(
+                _ty: &crash_orm::postgres::types::Type,
+                raw: &'a [u8],
+            ) -> std::result::Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                crash_orm::db_enum::db_enum_from_sql(raw)
+            }
+
+            fn accepts(ty: &crash_orm::postgres::types::Type) -> bool {
+                crash_orm::db_enum::db_enum_accepts::<#ident>(ty)
+            }
+        }
+    };
+
+    output.into()
+}
+
+/// Returns the name set with `#[db_enum(name = "...")]`.
+fn get_db_enum_name(attrs: &[Attribute]) -> Option<String> {
+    let attribute = attrs.iter().find(|a| a.path().is_ident("db_enum"))?;
+
+    let mut name = None;
+    attribute.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported db_enum attribute, expected name = \"...\""))
+        }
+    }).unwrap_or_else(|e| panic!("{}", e));
+
+    name
+}
@@ -4,7 +4,7 @@ use quote::quote;
 use syn::__private::Span;
 use syn::{parse_macro_input, Attribute, Data, DeriveInput, Ident};
 use crate::reserved_keywords::escape_reserved_keywords;
-use crate::util::{extract_generic_type, extract_generic_type_ignore_option, get_attribute_by_name, get_type_string, ident_to_table_name, is_relation, is_relation_value_holder, string_to_table_name};
+use crate::util::{extract_generic_type, extract_generic_type_ignore_option, get_attribute_by_name, get_generated_attribute, get_type_string, ident_to_table_name, is_relation, is_relation_value_holder, string_to_table_name};
 
 pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -105,7 +105,7 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
                     async fn #get_function_ident(&self, connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<Vec<#entity_type>> {
                         let rows = connection.query_many(#query, &[&self.#primary_key_ident]).await?;
                         use crash_orm::prelude::{Entity, ResultMapping};
-                        Ok(rows.into_iter().map(|v| #entity_type::from_row(v)).filter(|r| r.is_some()).map(|r| r.unwrap()).collect::<Vec<#entity_type>>())
+                        rows.into_iter().map(|v| #entity_type::from_row(v).map_err(|e| e.with_statement(#query))).collect::<crash_orm::Result<Vec<#entity_type>>>()
                     }
                 });
 
@@ -204,7 +204,7 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
                         use crash_orm::prelude::{Entity, ResultMapping};
                         let row = connection.query_single(#query, &[&self.#primary_key_ident]).await?;
                         if let Some(row) = row {
-                            Ok(#entity_type::from_row(row))
+                            Ok(Some(#entity_type::from_row(row).map_err(|e| e.with_statement(#query))?))
                         } else {
                             Ok(None)
                         }
@@ -226,7 +226,12 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
             pub const #field_ident_upper: crash_orm::prelude::EntityColumn::<#field_type, #ident> = crash_orm::prelude::EntityColumn::new(#field_ident_str_escaped);
         });
 
-        if field_ident_str != primary_field_name {
+        if get_generated_attribute(&field).is_some() {
+            // Generated columns are computed by the database and can't be written
+            create_fields_mapping.extend(quote! {
+                #field_ident: Default::default(),
+            });
+        } else if field_ident_str != primary_field_name {
             if is_relation_value_holder(&field_type) {
                 let field_ident_upper_id = Ident::new(
                     &*format!("{}_PRIMARY", field_ident_str.to_uppercase()),
@@ -345,7 +350,9 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
             ident_str, update_fields.join(","), primary_field_name, insert_index
         );
         quote! {
-            connection.execute_query(#update_string,&[#update_field_self_values &self.#primary_key_ident]).await?;
+            if connection.execute_query(#update_string,&[#update_field_self_values &self.#primary_key_ident]).await? == 0 && !connection.is_recording() {
+                return Err(crash_orm::Error::NotFound { statement: String::from(#update_string) });
+            }
         }
     };
 
@@ -402,22 +409,31 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
             async fn get_all(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<Vec<#ident>> {
                 let rows = connection.query_many(#select_all_string, &[]).await?;
                 use crash_orm::prelude::ResultMapping;
-                Ok(rows.into_iter().map(|v| Self::from_row(v)).filter(|r| r.is_some()).map(|r| r.unwrap()).collect::<Vec<Self>>())
+                rows.into_iter().map(|v| Self::from_row(v).map_err(|e| e.with_statement(#select_all_string))).collect::<crash_orm::Result<Vec<Self>>>()
             }
 
             async fn count(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<i64> {
-                let row = connection.query_single(#count_string, &[]).await?.unwrap();
+                let row = connection.query_single(#count_string, &[]).await?
+                    .ok_or_else(|| crash_orm::Error::String(format!("{} returned no row", #count_string)))?;
                 Ok(row.get(0))
             }
 
             async fn insert(&mut self, connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<()> {
-                let row = connection.query_single(#insert_string,&[#insert_field_values]).await?.unwrap();
-                self.#primary_key_ident = row.get(0);
+                match connection.query_single(#insert_string,&[#insert_field_values]).await? {
+                    Some(row) => self.#primary_key_ident = row.get(0),
+                    // The statement is only recorded in capture mode, so the primary key is kept
+                    None if connection.is_recording() => {}
+                    None => return Err(crash_orm::Error::String(format!("{} returned no row", #insert_string))),
+                }
+
                 Ok(())
             }
 
             async fn remove(&self, connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<()> {
-                connection.execute_query(#delete_string, &[&self.#primary_key_ident]).await?;
+                if connection.execute_query(#delete_string, &[&self.#primary_key_ident]).await? == 0 && !connection.is_recording() {
+                    return Err(crash_orm::Error::NotFound { statement: String::from(#delete_string) });
+                }
+
                 Ok(())
             }
 
@@ -438,7 +454,7 @@ pub fn derive_entity_impl(input: TokenStream) -> TokenStream {
                 let row = connection.query_single(#select_by_id_string, &[&#primary_key_ident]).await?;
                 if let Some(row) = row {
                     use crash_orm::prelude::{Entity, ResultMapping};
-                    Ok(Self::from_row(row))
+                    Ok(Some(Self::from_row(row).map_err(|e| e.with_statement(#select_by_id_string))?))
                 } else {
                     Ok(None)
                 }
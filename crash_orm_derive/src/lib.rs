@@ -1,8 +1,10 @@
 extern crate proc_macro;
 
+use crate::db_enum_derive::derive_db_enum_impl;
 use crate::entity_derive::derive_entity_impl;
 use crate::result_mapping_derive::derive_result_mapping_impl;
 use crate::schema_derive::derive_schema_impl;
+use crate::sql_migration_macro::include_sql_migrations_impl;
 use proc_macro::TokenStream;
 
 mod entity_derive;
@@ -10,23 +12,48 @@ mod schema_derive;
 mod util;
 mod result_mapping_derive;
 mod reserved_keywords;
+mod sql_migration_macro;
+mod db_enum_derive;
 
 #[cfg(all(feature = "uuid-gen-v4", feature = "uuid-gen-v7"))]
 compile_error!("Conflicting features: You cannot have gen-uuid-v4 and gen-uuid-v7 active at the same time!");
 
-#[proc_macro_derive(Entity, attributes(mapped_by, primary_key))]
+#[proc_macro_derive(Entity, attributes(mapped_by, primary_key, generated))]
 pub fn derive_entity(input: TokenStream) -> TokenStream {
     let mut output = derive_entity_impl(input.clone());
     output.extend(derive_result_mapping_impl(input));
     output
 }
 
-#[proc_macro_derive(Schema, attributes(primary_key))]
+#[proc_macro_derive(Schema, attributes(primary_key, sql_type, exclude, generated, index))]
 pub fn derive_schema(input: TokenStream) -> TokenStream {
     derive_schema_impl(input)
 }
 
-#[proc_macro_derive(ResultMapping)]
+#[proc_macro_derive(ResultMapping, attributes(column, default, flatten))]
 pub fn derive_result_mapping(input: TokenStream) -> TokenStream {
     derive_result_mapping_impl(input)
 }
+
+#[proc_macro_derive(DbEnum, attributes(db_enum))]
+pub fn derive_db_enum(input: TokenStream) -> TokenStream {
+    derive_db_enum_impl(input)
+}
+
+/// Embeds all SQL migrations of a directory (relative to the crate root) at compile time.
+///
+/// Expands to a `Vec<Box<dyn Migration>>` of `SqlMigration`s, ordered by their numeric prefix.
+/// Files must be named `NNNN_name.up.sql` and optionally `NNNN_name.down.sql`.
+///
+/// Cargo only tracks the embedded files, so adding or removing a migration does not rebuild your crate.
+/// Add a `build.rs` to your crate, which tells cargo to watch the directory:
+///
+/// ```ignore
+/// fn main() {
+///     println!("cargo:rerun-if-changed=migrations");
+/// }
+/// ```
+#[proc_macro]
+pub fn include_sql_migrations(input: TokenStream) -> TokenStream {
+    include_sql_migrations_impl(input)
+}
@@ -1,7 +1,7 @@
-use crate::util::{extract_generic_type, get_type_string, is_relation};
+use crate::util::{extract_generic_type, get_attribute_by_name, get_type_string, is_relation};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, Field, LitStr};
 
 pub(crate) fn derive_result_mapping_impl(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -11,12 +11,20 @@ pub(crate) fn derive_result_mapping_impl(input: TokenStream) -> TokenStream {
 
     let ident = derive_input.ident;
     let mut select_fields = quote!();
-    let mut all_index = 0usize;
 
     for field in struct_data.fields {
         let field_ident = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
 
+        // Checked first, as flattened types like tuples are no paths
+        if get_attribute_by_name(&field, "flatten").is_some() {
+            select_fields.extend(quote! {
+                #field_ident: <#field_type as crash_orm::result_mapping::ResultMapping>::from_row_ref(row)?,
+            });
+
+            continue;
+        }
+
         if is_relation(field_type) {
             let field_type_name = get_type_string(field_type);
             let field_type_name = if field_type_name == "Option" {
@@ -39,22 +47,51 @@ pub(crate) fn derive_result_mapping_impl(input: TokenStream) -> TokenStream {
             }
         }
 
-        select_fields.extend(quote! {
-            #field_ident: row.try_get(#all_index).ok()?,
-        });
+        let column_name = get_column_name(&field);
 
-        all_index += 1;
+        if get_attribute_by_name(&field, "default").is_some() {
+            select_fields.extend(quote! {
+                #field_ident: crash_orm::result_mapping::try_get_named_column_or_default(row, #column_name)?,
+            });
+        } else {
+            select_fields.extend(quote! {
+                #field_ident: crash_orm::result_mapping::try_get_named_column(row, #column_name)?,
+            });
+        }
     }
-    
+
     let output = quote! {
         impl crash_orm::result_mapping::ResultMapping for #ident {
-            fn from_row(row: crash_orm::postgres::Row) -> Option<#ident> {
-                Some(#ident {
+            fn from_row(row: crash_orm::postgres::Row) -> crash_orm::Result<#ident> {
+                Self::from_row_ref(&row)
+            }
+
+            fn from_row_ref(row: &crash_orm::postgres::Row) -> crash_orm::Result<#ident> {
+                Ok(#ident {
                     #select_fields
                 })
             }
         }
     };
-    
+
     output.into()
-}
\ No newline at end of file
+}
+
+/// Returns the column name of a field, which is the field name unless set with `#[column(name = "...")]`.
+fn get_column_name(field: &Field) -> String {
+    let Some(attribute) = get_attribute_by_name(field, "column") else {
+        return field.ident.as_ref().unwrap().to_string();
+    };
+
+    let mut name = None;
+    attribute.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported column attribute, expected name = \"...\""))
+        }
+    }).unwrap_or_else(|e| panic!("{}", e));
+
+    name.expect("The attribute \"column\" requires a name, e.g. #[column(name = \"...\")]")
+}
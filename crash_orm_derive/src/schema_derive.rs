@@ -1,9 +1,9 @@
 use proc_macro::TokenStream;
 
 use crate::reserved_keywords::escape_reserved_keywords;
-use crate::util::{get_attribute_by_name, get_type_string, ident_to_table_name, rust_to_postgres_type};
+use crate::util::{get_attribute_by_name, get_generated_attribute, get_sql_type, get_sql_type_attribute, get_type_string, ident_to_table_name, rust_to_postgres_type};
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr, Meta, Type};
 
 pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -11,11 +11,15 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
         panic!("only structs are supported")
     };
 
-    let mut create_fields_string = String::new();
+    let mut create_string = CreateString::default();
+    let index_strings = get_indexes(&derive_input.attrs, &struct_data.fields, &ident_to_table_name(&derive_input.ident));
 
     let ident = derive_input.ident;
     let ident_str = ident_to_table_name(&ident);
+    create_string.push_str(&format!("CREATE TABLE public.{}(", ident_str));
     let mut id_is_uuid = false;
+    let mut sql_types = vec![];
+    let mut column_definitions = vec![];
 
     let primary_field_name = {
         let mut defined_primary_key = None;
@@ -41,14 +45,53 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
 
     for field in struct_data.fields {
         let field_name = field.ident.clone().unwrap().to_string();
-        let column_type = rust_to_postgres_type(&field.ty, &*field_name);
+        let sql_type_attribute = get_sql_type_attribute(&field);
+        let has_sql_type_attribute = sql_type_attribute.is_some();
+        let column_type = rust_to_postgres_type(&field.ty, &*field_name, sql_type_attribute);
 
         if column_type.is_none() {
             continue;
         }
         let column_type = column_type.unwrap();
 
-        create_fields_string.push_str(&*format!("{} {}", escape_reserved_keywords(&field_name), column_type));
+        create_string.push_str(&format!("{} ", escape_reserved_keywords(&field_name)));
+
+        let sql_type = get_sql_type(&field.ty);
+        let runtime_sql_type = sql_type.as_ref().filter(|_| !has_sql_type_attribute);
+        create_string.push_column_type(&column_type, runtime_sql_type);
+
+        // The column type ends with its null constraint and may reference another table
+        let (type_name, nullable) = match column_type.strip_suffix(" NOT NULL") {
+            Some(type_name) => (type_name, false),
+            None => (column_type.strip_suffix(" NULL").unwrap(), true),
+        };
+        let type_name = type_name.split_once(" REFERENCES ").map_or(type_name, |(type_name, _)| type_name);
+        let mut column_type_string = CreateString::default();
+        column_type_string.push_column_type(type_name, runtime_sql_type);
+        let column_type_quote = column_type_string.into_quote();
+        let primary_quote = if field_name == primary_field_name {
+            quote!(.primary())
+        } else {
+            quote!()
+        };
+        column_definitions.push(quote! {
+            .add_column(
+                crash_orm::schema::ColumnDefinition::new(
+                    #field_name,
+                    crash_orm::schema::ColumnDefinition::resolve_type(connection, #column_type_quote).await?,
+                    #nullable,
+                )#primary_quote
+            )?
+        });
+
+        // The type is also created for fields with #[sql_type], as the overridden type may depend on it
+        if let Some(sql_type) = sql_type {
+            sql_types.push(sql_type);
+        }
+
+        if let Some(expression) = get_generated_attribute(&field) {
+            create_string.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", expression));
+        }
 
         if &*field_name == primary_field_name {
             let field_type_str = get_type_string(&field.ty);
@@ -59,7 +102,7 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
 
             // Uuid should not be generated by the database
             if field_type_str != "Uuid" {
-                create_fields_string.push_str(&*format!(
+                create_string.push_str(&*format!(
                     " DEFAULT nextval('{}_{}_seq'::regclass)",
                     ident_str, primary_field_name,
                 ));
@@ -68,15 +111,17 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
             }
         }
 
-        create_fields_string.push_str(",");
+        create_string.push_str(",");
     }
 
-    create_fields_string.push_str(&*format!("PRIMARY KEY ({})", primary_field_name));
+    create_string.push_str(&*format!("PRIMARY KEY ({})", primary_field_name));
 
-    let create_string = format!(
-        "CREATE TABLE public.{}({});",
-        ident_str, create_fields_string
-    );
+    for exclude in get_exclude_constraints(&derive_input.attrs) {
+        create_string.push_str(&format!(",{}", exclude));
+    }
+
+    create_string.push_str(");");
+    let create_string_quote = create_string.into_quote();
 
     let sequence_create_quote = if !id_is_uuid {
         let sequence_create = format!("CREATE SEQUENCE {}_{}_seq", ident_str, primary_field_name);
@@ -106,13 +151,19 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
         #[crash_orm::async_trait::async_trait]
         impl crash_orm::prelude::Schema for #ident {
             async fn create_table(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<()> {
+                #(<#sql_types as crash_orm::sql_type::SchemaColumnType>::before_create_column(connection).await?;)*
                 #sequence_create_quote
-                connection.execute_query(#create_string, &[]).await?;
+                connection.execute_query(#create_string_quote, &[]).await?;
                 #sequence_created_alter_quote
+                #(connection.execute_query(#index_strings, &[]).await?;)*
 
                 Ok(())
             }
 
+            async fn table_definition(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<crash_orm::schema::TableDefinition> {
+                Ok(crash_orm::schema::TableDefinition::new(#ident_str)#(#column_definitions)*)
+            }
+
             async fn drop_table(connection: &impl crash_orm::prelude::DatabaseConnection) -> crash_orm::Result<()> {
                 connection.execute_query(#drop_string, &[]).await?;
 
@@ -135,3 +186,140 @@ pub fn derive_schema_impl(input: TokenStream) -> TokenStream {
 
     output.into()
 }
+
+/// Create statement of a table or column type, whose column types of SchemaColumnType are only known at runtime.
+#[derive(Default)]
+struct CreateString {
+    parts: Vec<CreatePart>,
+}
+
+enum CreatePart {
+    Literal(String),
+    SqlType(Type),
+}
+
+impl CreateString {
+    fn push_str(&mut self, string: &str) {
+        match self.parts.last_mut() {
+            Some(CreatePart::Literal(literal)) => literal.push_str(string),
+            _ => self.parts.push(CreatePart::Literal(string.to_string())),
+        }
+    }
+
+    fn push_sql_type(&mut self, sql_type: &Type) {
+        self.parts.push(CreatePart::SqlType(sql_type.clone()));
+    }
+
+    /// Pushes the column type, whose placeholder `{}` is replaced by the type of SchemaColumnType if `sql_type` is set.
+    fn push_column_type(&mut self, column_type: &str, sql_type: Option<&Type>) {
+        match sql_type {
+            Some(sql_type) => {
+                let (prefix, suffix) = column_type.split_once("{}").unwrap();
+                self.push_str(prefix);
+                self.push_sql_type(sql_type);
+                self.push_str(suffix);
+            }
+            None => self.push_str(column_type),
+        }
+    }
+
+    /// Returns the statement as string literal or, if it contains types of SchemaColumnType, as concatenation of its parts.
+    fn into_quote(self) -> impl quote::ToTokens {
+        if let [CreatePart::Literal(literal)] = &*self.parts {
+            return quote!(#literal);
+        }
+
+        let parts = self.parts.into_iter().map(|part| match part {
+            CreatePart::Literal(literal) => quote!(#literal),
+            CreatePart::SqlType(sql_type) => quote!(<#sql_type as crash_orm::sql_type::SchemaColumnType>::COLUMN_TYPE),
+        });
+        quote!(&[#(#parts),*].concat())
+    }
+}
+
+/// Returns the exclusion constraints set with `#[exclude(using = "gist", columns(column = "operator", ...))]`.
+fn get_exclude_constraints(attrs: &[Attribute]) -> Vec<String> {
+    attrs.iter().filter(|a| a.path().is_ident("exclude")).map(|attribute| {
+        let mut using = String::from("gist");
+        let mut name = None;
+        let mut elements = vec![];
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("using") {
+                using = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("columns") {
+                meta.parse_nested_meta(|column| {
+                    let column_name = column.path.get_ident()
+                        .ok_or_else(|| column.error("expected column name"))?
+                        .to_string();
+                    let operator = column.value()?.parse::<LitStr>()?.value();
+                    elements.push(format!("{} WITH {}", escape_reserved_keywords(&column_name), operator));
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unsupported exclude attribute, expected using, name or columns"))
+            }
+        }).unwrap_or_else(|e| panic!("{}", e));
+
+        if elements.is_empty() {
+            panic!("The attribute \"exclude\" requires columns, e.g. #[exclude(columns(during = \"&&\"))]");
+        }
+
+        let constraint = name.map(|name| format!("CONSTRAINT {} ", name)).unwrap_or_default();
+        format!("{}EXCLUDE USING {} ({})", constraint, using, elements.join(","))
+    }).collect()
+}
+
+/// Returns the create statements of the indexes set with `#[index(using = "gin", columns(column, ...))]` on the struct
+/// or `#[index(using = "gin")]` on a field.
+fn get_indexes(attrs: &[Attribute], fields: &Fields, table_name: &str) -> Vec<String> {
+    let field_indexes = fields.iter().flat_map(|field| {
+        let column_name = escape_reserved_keywords(&field.ident.as_ref().unwrap().to_string());
+        field.attrs.iter().filter(|a| a.path().is_ident("index")).map(move |attribute| (attribute, Some(column_name.clone())))
+    });
+
+    attrs.iter().filter(|a| a.path().is_ident("index")).map(|attribute| (attribute, None)).chain(field_indexes).map(|(attribute, column)| {
+        let mut using = String::from("btree");
+        let mut name = None;
+        let mut elements = column.into_iter().collect::<Vec<_>>();
+
+        // A plain #[index] on a field uses the defaults
+        let Meta::List(_) = attribute.meta else {
+            return format!("CREATE INDEX ON public.{} USING {} ({})", table_name, using, elements.join(","));
+        };
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("using") {
+                using = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("expression") {
+                elements.push(format!("({})", meta.value()?.parse::<LitStr>()?.value()));
+                Ok(())
+            } else if meta.path.is_ident("columns") {
+                meta.parse_nested_meta(|column| {
+                    let column_name = column.path.get_ident()
+                        .ok_or_else(|| column.error("expected column name"))?
+                        .to_string();
+                    elements.push(escape_reserved_keywords(&column_name));
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unsupported index attribute, expected using, name, columns or expression"))
+            }
+        }).unwrap_or_else(|e| panic!("{}", e));
+
+        if elements.is_empty() {
+            panic!("The attribute \"index\" requires columns or an expression, e.g. #[index(using = \"gin\", columns(search))]");
+        }
+
+        let name = name.map(|name| format!("{} ", name)).unwrap_or_default();
+        format!("CREATE INDEX {}ON public.{} USING {} ({})", name, table_name, using, elements.join(","))
+    }).collect()
+}
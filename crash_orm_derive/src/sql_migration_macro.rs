@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+pub fn include_sql_migrations_impl(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr).value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let dir = PathBuf::from(manifest_dir).join(dir);
+
+    let entries = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Failed to read migration directory {}: {}", dir.display(), e));
+
+    let mut files = vec![];
+    for entry in entries {
+        let entry = entry.unwrap_or_else(|e| panic!("Failed to read migration directory {}: {}", dir.display(), e));
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        let (name, is_up) = if let Some(name) = file_name.strip_suffix(".up.sql") {
+            (name.to_string(), true)
+        } else if let Some(name) = file_name.strip_suffix(".down.sql") {
+            (name.to_string(), false)
+        } else {
+            continue;
+        };
+
+        let Some(number) = name.split_once('_').and_then(|(number, _)| number.parse::<u64>().ok()) else {
+            panic!("SQL migration {} must be named NNNN_name.up.sql or NNNN_name.down.sql", file_name);
+        };
+
+        files.push((number, name, is_up, entry.path().to_string_lossy().to_string()));
+    }
+
+    files.sort_by(|(number_a, name_a, _, _), (number_b, name_b, _, _)| {
+        number_a.cmp(number_b).then_with(|| name_a.cmp(name_b))
+    });
+
+    let mut migrations = quote!();
+    for (_, name, is_up, path) in &files {
+        if !is_up {
+            if !files.iter().any(|(_, other, other_is_up, _)| *other_is_up && other == name) {
+                panic!("Found down migration {} without matching up migration", name);
+            }
+            continue;
+        }
+
+        let down = files.iter()
+            .find(|(_, other, other_is_up, _)| !*other_is_up && other == name)
+            .map(|(_, _, _, down_path)| quote! { .with_down(include_str!(#down_path)) })
+            .unwrap_or_default();
+
+        migrations.extend(quote! {
+            Box::new(crash_orm::migration::SqlMigration::new(#name, include_str!(#path))#down) as Box<dyn crash_orm::migration::Migration<_>>,
+        });
+    }
+
+    let output = quote! {
+        {
+            let migrations: Vec<Box<dyn crash_orm::migration::Migration<_>>> = vec![
+                #migrations
+            ];
+            migrations
+        }
+    };
+
+    output.into()
+}
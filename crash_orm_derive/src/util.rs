@@ -1,6 +1,6 @@
 use convert_case::{Case, Casing};
 use quote::ToTokens;
-use syn::{Attribute, Field, GenericArgument, Ident, PathArguments, Type};
+use syn::{Attribute, Expr, ExprLit, Field, GenericArgument, Ident, Lit, Meta, MetaNameValue, PathArguments, Type};
 
 pub(crate) fn extract_generic_type_ignore_option(ty: &Type, number: usize) -> Option<Type> {
     if get_type_string(ty) == "Option" {
@@ -59,8 +59,11 @@ pub(crate) fn is_relation(field_type: &Type) -> bool {
     }
 }
 
-pub(crate) fn rust_to_postgres_type(field_type: &Type, field_name: &str) -> Option<String> {
-    let (str, nullable) = _rust_to_postgres_type(field_type)?;
+pub(crate) fn rust_to_postgres_type(field_type: &Type, field_name: &str, sql_type: Option<String>) -> Option<String> {
+    let (str, nullable) = match sql_type {
+        Some(sql_type) => (sql_type, get_type_string(field_type) == "Option"),
+        None => _rust_to_postgres_type(field_type)?,
+    };
 
     Some(if nullable && field_name != "id" {
         format!("{} NULL", str)
@@ -79,10 +82,12 @@ fn _rust_to_postgres_type(field_type: &Type) -> Option<(String, bool)> {
         "i32" => "int4",
         "i64" => "int8",
         "u32" => "oid",
+        "u8" | "u16" | "u64" | "u128" | "usize" => {
+            panic!("{} is not supported, as Postgres has no unsigned types. Use the next larger signed type instead, e.g. i32 for u16", path)
+        }
         "f32" => "float4",
         "f64" => "float8",
         "String" => "text",
-        "Decimal" => "numeric",
         "OneToOne" => {
             let target_entity = extract_generic_type(field_type, 1).unwrap();
             let (target_type, _) = _rust_to_postgres_type(&extract_generic_type(field_type, 2).unwrap()).unwrap();
@@ -117,26 +122,58 @@ fn _rust_to_postgres_type(field_type: &Type) -> Option<(String, bool)> {
             let (res, _) = _rust_to_postgres_type(&extract_generic_type(field_type, 1).unwrap())?;
             return Some((res, true));
         }
-        "DateTime" => "timestamp with time zone",
-        "NaiveDate" => "date",
-        "NaiveTime" => "time",
-        "Uuid" => "uuid",
-        "TypedJson" => "jsonb",
-        "Value" => "jsonb",
-        "MacAddress" => "macaddr",
-        "PrimitiveDateTime" => "timestamp",
-        "OffsetDateTime" => "timestamp with time zone",
-        "Date" => "date",
-        "Time" => "time",
-        "Point" => "point",
-        "Rect" => "box",
-        "LineString" => "path",
-        _ => panic!("unsupported type {}", path),
+        "Vec" => {
+            let element_type = extract_generic_type(field_type, 1).unwrap();
+            if get_type_string(&element_type) == "u8" {
+                "bytea"
+            } else {
+                let (element_type, _) = _rust_to_postgres_type(&element_type)?;
+                return Some((format!("{}[]", element_type), false));
+            }
+        }
+        // All other types, including those of other crates, provide their type at runtime
+        // with SchemaColumnType, as e.g. a user type named Date must not be mapped to date
+        _ => "{}",
     };
 
     Some((column_type.to_string(), false))
 }
 
+/// Returns the type of a field, whose postgres type is provided by `SchemaColumnType` at runtime.
+///
+/// An [Option], the element type of a [Vec] and the key of a relation are unwrapped.
+pub(crate) fn get_sql_type(field_type: &Type) -> Option<Type> {
+    let (column_type, _) = _rust_to_postgres_type(field_type)?;
+    if !column_type.contains("{}") {
+        return None;
+    }
+
+    match &*get_type_string(field_type) {
+        "Option" | "Vec" => get_sql_type(&extract_generic_type(field_type, 1).unwrap()),
+        "OneToOne" | "ManyToOne" => get_sql_type(&extract_generic_type(field_type, 2).unwrap()),
+        _ => Some(field_type.clone()),
+    }
+}
+
+/// Returns the type set with `#[sql_type = "..."]`.
+pub(crate) fn get_sql_type_attribute(field: &Field) -> Option<String> {
+    let attribute = get_attribute_by_name(field, "sql_type")?;
+
+    match &attribute.meta {
+        Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Str(value), .. }), .. }) => Some(value.value()),
+        _ => panic!("The attribute \"sql_type\" requires a type, e.g. #[sql_type = \"citext\"]"),
+    }
+}
+
+pub(crate) fn get_generated_attribute(field: &Field) -> Option<String> {
+    let attribute = get_attribute_by_name(field, "generated")?;
+
+    match &attribute.meta {
+        Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Str(value), .. }), .. }) => Some(value.value()),
+        _ => panic!("The attribute \"generated\" requires an expression, e.g. #[generated = \"to_tsvector('english', description)\"]"),
+    }
+}
+
 pub(crate) fn ident_to_table_name(ident: &Ident) -> String {
     string_to_table_name(ident.to_string())
 }
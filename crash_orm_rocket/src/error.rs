@@ -0,0 +1,46 @@
+use rocket::http::Status;
+use rocket::response::Responder;
+use rocket::{Request, response};
+
+/// Wrapper of [crash_orm::Error] which can be returned by rocket handlers.
+///
+/// The error is mapped to the HTTP status of [CrashOrmError::status], the error itself is not sent to the client.
+/// Use `?` to convert a [crash_orm::Error] inside a handler returning `Result<T, CrashOrmError>`.
+#[derive(Debug)]
+pub struct CrashOrmError(pub crash_orm::Error);
+
+impl CrashOrmError {
+    /// Returns the HTTP status for the error.
+    ///
+    /// - [NotFound](crash_orm::Error::NotFound): 404 Not Found
+    /// - [UniqueViolation](crash_orm::Error::UniqueViolation), [ForeignKeyViolation](crash_orm::Error::ForeignKeyViolation),
+    ///   [ExclusionViolation](crash_orm::Error::ExclusionViolation), [SerializationFailure](crash_orm::Error::SerializationFailure), [Deadlock](crash_orm::Error::Deadlock): 409 Conflict
+    /// - [CheckViolation](crash_orm::Error::CheckViolation): 422 Unprocessable Entity
+    /// - [ConnectionLost](crash_orm::Error::ConnectionLost): 503 Service Unavailable
+    /// - all other errors: 500 Internal Server Error
+    pub fn status(&self) -> Status {
+        match self.0 {
+            crash_orm::Error::NotFound { .. } => Status::NotFound,
+            crash_orm::Error::UniqueViolation { .. }
+            | crash_orm::Error::ForeignKeyViolation { .. }
+            | crash_orm::Error::ExclusionViolation { .. }
+            | crash_orm::Error::SerializationFailure { .. }
+            | crash_orm::Error::Deadlock { .. } => Status::Conflict,
+            crash_orm::Error::CheckViolation { .. } => Status::UnprocessableEntity,
+            crash_orm::Error::ConnectionLost { .. } => Status::ServiceUnavailable,
+            _ => Status::InternalServerError,
+        }
+    }
+}
+
+impl From<crash_orm::Error> for CrashOrmError {
+    fn from(value: crash_orm::Error) -> Self {
+        Self(value)
+    }
+}
+
+impl<'r> Responder<'r, 'static> for CrashOrmError {
+    fn respond_to(self, _request: &'r Request<'_>) -> response::Result<'static> {
+        Err(self.status())
+    }
+}
@@ -3,6 +3,8 @@
 //! To use this crate, attach the [CrashOrmDatabaseFairing] or [CrashOrmDatabaseMigrationFairing] to your rocket instance.
 //!
 //! You can then access your configured connection with the request guard `&State<CrashOrmDatabaseConnection>`.
+//!
+//! Return a [CrashOrmError] from your handlers to map errors of the ORM to HTTP statuses.
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -12,8 +14,10 @@ mod fairing;
 #[cfg(feature = "migration")]
 mod fairing_migration;
 mod conn;
+mod error;
 
 pub use fairing::*;
+pub use error::*;
 
 #[cfg(feature = "migration")]
 pub use fairing_migration::*;
@@ -37,6 +37,12 @@ async fn test_crud_operation() {
 
     let response = client.delete(format!("/test/delete/{}", id)).dispatch().await;
     assert_eq!(response.status(), Status::Ok);
+
+    let response = client.post("/test/update").json(&TestCrud {
+        id,
+        name: "test123".to_string(),
+    }).dispatch().await;
+    assert_eq!(response.status(), Status::NotFound);
 }
 
 #[derive(Entity, Schema, Debug, Serialize, Deserialize, CRUD)]
@@ -14,35 +14,35 @@ pub fn crud_impl(input: TokenStream) -> TokenStream {
             use super::{#ident, #create_ident};
 
             #[rocket::post("/create", data = "<json>")]
-            pub async fn create(json: rocket::serde::json::Json<#create_ident>, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> rocket::serde::json::Json<u32> {
+            pub async fn create(json: rocket::serde::json::Json<#create_ident>, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> std::result::Result<rocket::serde::json::Json<u32>, crash_orm_rocket::CrashOrmError> {
                 use crash_orm::entity::CreateEntity;
-                let element = json.0.insert(&**conn).await.unwrap();
+                let element = json.0.insert(&**conn).await?;
 
-                rocket::serde::json::Json(element.id)
+                Ok(rocket::serde::json::Json(element.id))
             }
 
             #[rocket::get("/get/<id>")]
-            pub async fn read(id: u32, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> rocket::serde::json::Json<Option<#ident>> {
+            pub async fn read(id: u32, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> std::result::Result<rocket::serde::json::Json<Option<#ident>>, crash_orm_rocket::CrashOrmError> {
                 use crash_orm::entity::PrimaryKeyEntity;
-                rocket::serde::json::Json(#ident::get_by_primary(&**conn, id).await.unwrap())
+                Ok(rocket::serde::json::Json(#ident::get_by_primary(&**conn, id).await?))
             }
 
             #[rocket::post("/update", data = "<json>")]
-            pub async fn update(json: rocket::serde::json::Json<#ident>, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> rocket::serde::json::Json<bool> {
+            pub async fn update(json: rocket::serde::json::Json<#ident>, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> std::result::Result<rocket::serde::json::Json<bool>, crash_orm_rocket::CrashOrmError> {
                 use crash_orm::entity::Entity;
-                json.0.update(&**conn).await.unwrap();
+                json.0.update(&**conn).await?;
 
-                rocket::serde::json::Json(true)
+                Ok(rocket::serde::json::Json(true))
             }
 
             #[rocket::delete("/delete/<id>")]
-            pub async fn delete(id: u32, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> rocket::serde::json::Json<bool> {
+            pub async fn delete(id: u32, conn: &rocket::State<crash_orm::connection::CrashOrmDatabaseConnection>) -> std::result::Result<rocket::serde::json::Json<bool>, crash_orm_rocket::CrashOrmError> {
                 use crash_orm::entity::{Entity, PrimaryKeyEntity};
-                if let Some(entity) = #ident::get_by_primary(&**conn, id).await.unwrap() {
-                    entity.remove(&**conn).await.unwrap();
+                if let Some(entity) = #ident::get_by_primary(&**conn, id).await? {
+                    entity.remove(&**conn).await?;
                 }
 
-                rocket::serde::json::Json(true)
+                Ok(rocket::serde::json::Json(true))
             }
         }
 